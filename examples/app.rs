@@ -10,7 +10,7 @@ fn main() {
     query.insert("query", "metallica");
     let results = mb.artist().search(&mb, &mut query).unwrap();
 
-    println!("{}", results[0]);
+    println!("{}", results.items[0].entity);
 
     query.clear();
     query.insert("inc", "release-groups+tags");
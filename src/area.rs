@@ -0,0 +1,175 @@
+use uuid::Uuid;
+use life_span::LifeSpan;
+use std::collections::HashMap;
+use std::fmt;
+use traits::{Entity, Browse};
+use browse::{LinkedEntity, Page};
+use error::Error;
+use parse;
+use include::{Include, Request};
+use paginated::Paginated;
+use search::SearchResult;
+use json::JsonValue;
+use relationship::Relationship;
+#[cfg(feature = "async")]
+use traits::AsyncEntity;
+#[cfg(feature = "async")]
+use async_client::{AsyncMusicBrainz, ApiFuture};
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub struct Area {
+    #[cfg_attr(feature = "serde", serde(with = "::serde_impls::uuid"))]
+    pub id: Uuid,
+    pub name: String,
+    #[cfg_attr(feature = "serde", serde(default, with = "::serde_impls::nullable"))]
+    pub sort_name: String,
+    #[cfg_attr(feature = "serde", serde(rename = "type", default, with = "::serde_impls::nullable"))]
+    pub area_type: String,
+    #[cfg_attr(feature = "serde", serde(default, with = "::serde_impls::nullable"))]
+    pub disambiguation: String,
+    /// ISO 3166-1 country codes, e.g. `CA`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub iso_3166_1_codes: Vec<String>,
+    /// ISO 3166-2 subdivision codes, e.g. `CA-ON`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub iso_3166_2_codes: Vec<String>,
+    /// ISO 3166-3 codes of countries that no longer exist, e.g. `SUHH`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub iso_3166_3_codes: Vec<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub life_span: LifeSpan,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub relations: Vec<Relationship>
+}
+
+impl Area {
+    pub fn new(id: Uuid, name: String, sort_name: String, area_type: String, disambiguation: String,
+               iso_3166_1_codes: Vec<String>, iso_3166_2_codes: Vec<String>, iso_3166_3_codes: Vec<String>,
+               life_span: LifeSpan) -> Area {
+        Area {
+            id: id,
+            name: name,
+            sort_name: sort_name,
+            area_type: area_type,
+            disambiguation: disambiguation,
+            iso_3166_1_codes: iso_3166_1_codes,
+            iso_3166_2_codes: iso_3166_2_codes,
+            iso_3166_3_codes: iso_3166_3_codes,
+            life_span: life_span,
+            relations: Vec::new()
+        }
+    }
+
+    pub fn empty() -> Area {
+        Area::new(
+            Uuid::nil(),
+            String::new(),
+            String::new(),
+            String::new(),
+            String::new(),
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+            LifeSpan::empty()
+        )
+    }
+
+    pub(crate) fn from_json(area: &JsonValue) -> Result<Area, Error> {
+        let id = parse::mbid(area, "id")?;
+
+        let mut result = Area::new(
+            id,
+            parse::string(area, "name")?,
+            area["sort-name"].as_str().unwrap_or("").to_string(),
+            area["type"].as_str().unwrap_or("").to_string(),
+            area["disambiguation"].as_str().unwrap_or("").to_string(),
+            parse::strings(area, "iso-3166-1-codes")?,
+            parse::strings(area, "iso-3166-2-codes")?,
+            parse::strings(area, "iso-3166-3-codes")?,
+            match LifeSpan::from_json(&area["life-span"]) {
+                Ok(x) => x,
+                Err(e) => return Err(e.within("life-span"))
+            }
+        );
+        result.relations = Relationship::from_json(area)?;
+        Ok(result)
+    }
+}
+
+impl PartialEq for Area {
+    fn eq(&self, other: &Area) -> bool {
+        self.id == other.id && self.name == other.name
+    }
+}
+
+impl fmt::Display for Area {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{name} ({type})", name=self.name, type=self.area_type)?;
+        writeln!(f, "Id: {id}", id=self.id)
+    }
+}
+
+/// Subqueries that can be included in a lookup request.
+const LOOKUP_INCLUDES: &'static [Include] = &[
+    Include::Aliases, Include::Annotation, Include::Tags, Include::Genres, Include::UserTags,
+    Include::UserGenres
+];
+
+/// Subqueries that can be included in a browse request.
+const BROWSE_INCLUDES: &'static [Include] = &[
+    Include::Aliases, Include::Annotation, Include::Tags, Include::Genres, Include::UserTags,
+    Include::UserGenres
+];
+
+impl Entity for Area {
+    fn search(&self, client: &super::MusicBrainz, params: &mut HashMap<&str, &str>) -> Result<Page<SearchResult<Self>>, Error> {
+        client.search("area", "areas", params, Area::from_json)
+    }
+
+    fn lookup(&self, client: &super::MusicBrainz, entity_id: &Uuid, params: &mut HashMap<&str, &str>) -> Result<Self, Error> {
+        let area_data = match client.get(&format!("area/{id}", id=entity_id), params) {
+            Ok(x) => x,
+            Err(e) => return Err(e)
+        };
+
+        Area::from_json(&area_data)
+    }
+
+    fn search_all<'a>(&self, client: &'a super::MusicBrainz, params: &HashMap<&str, &str>) -> Paginated<'a, SearchResult<Self>> {
+        client.search_all("area", "areas", params, Area::from_json)
+    }
+
+    fn includes(&self, request: Request) -> &'static [Include] {
+        match request {
+            Request::Lookup => LOOKUP_INCLUDES,
+            Request::Browse => BROWSE_INCLUDES,
+            _ => &[]
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncEntity for Area {
+    fn search_async(&self, client: &AsyncMusicBrainz, params: &HashMap<&str, &str>) -> ApiFuture<Page<SearchResult<Self>>> {
+        client.search("area", "areas", params, Area::from_json)
+    }
+
+    fn lookup_async(&self, client: &AsyncMusicBrainz, entity_id: &Uuid, params: &HashMap<&str, &str>) -> ApiFuture<Self> {
+        client.lookup(&format!("area/{id}", id=entity_id), params, Area::from_json)
+    }
+}
+
+/// Entities that can be linked to in a browse request.
+const BROWSE_BY: &'static [&'static str] = &["collection"];
+
+impl Browse for Area {
+    fn browse(&self, client: &super::MusicBrainz, linked: &LinkedEntity, params: &mut HashMap<&str, &str>) -> Result<Page<Self>, Error> {
+        client.browse("area", "areas", BROWSE_BY, linked, params, Area::from_json)
+    }
+
+    fn browse_all<'a>(&self, client: &'a super::MusicBrainz, linked: &LinkedEntity, params: &HashMap<&str, &str>) -> Paginated<'a, Self> {
+        client.browse_all("area", "areas", BROWSE_BY, linked, params, Area::from_json)
+    }
+}
@@ -1,19 +1,39 @@
 use release_group::ReleaseGroup;
 use uuid::Uuid;
-use enums::{PersonType, AlbumType};
+use enums::PersonType;
 use std::fmt;
 use std::collections::HashMap;
-use traits::Entity;
+use traits::{Entity, Browse};
+use browse::{LinkedEntity, Page};
 use error::Error;
+use parse;
+use include::{Include, Request};
+use paginated::Paginated;
+use search::SearchResult;
+use json::JsonValue;
+use relationship::Relationship;
+#[cfg(feature = "async")]
+use traits::AsyncEntity;
+#[cfg(feature = "async")]
+use async_client::{AsyncMusicBrainz, ApiFuture};
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub struct Artist {
+    #[cfg_attr(feature = "serde", serde(with = "::serde_impls::uuid"))]
     pub id: Uuid,
     pub name: String,
+    #[cfg_attr(feature = "serde", serde(default, with = "::serde_impls::nullable"))]
     pub gender: String,
+    #[cfg_attr(feature = "serde", serde(rename = "type", default, with = "::serde_impls::nullable"))]
     pub artist_type: PersonType,
+    #[cfg_attr(feature = "serde", serde(default, with = "::serde_impls::names"))]
     pub tags: Vec<String>,
-    pub release_groups: Vec<ReleaseGroup>
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub release_groups: Vec<ReleaseGroup>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub relations: Vec<Relationship>
 }
 
 impl Artist {
@@ -24,7 +44,8 @@ impl Artist {
             gender: gender,
             artist_type: artist_type,
             tags: tags,
-            release_groups: release_groups
+            release_groups: release_groups,
+            relations: Vec::new()
         }
     }
 
@@ -38,6 +59,27 @@ impl Artist {
             Vec::new()
         )
     }
+
+    pub(crate) fn from_json(artist: &JsonValue) -> Result<Artist, Error> {
+        let artist_type = parse::value(artist, "type", PersonType::Other)?;
+
+        let tags = parse::list(artist, "tags", |tag| parse::string(tag, "name"))?;
+
+        let artist_id = parse::mbid(artist, "id")?;
+
+        let artist_albums = parse::list(artist, "release-groups", ReleaseGroup::from_json)?;
+
+        let mut result = Artist::new(
+            artist_id,
+            parse::string(artist, "name")?,
+            artist["gender"].as_str().unwrap_or("").to_string(),
+            artist_type,
+            tags,
+            artist_albums
+        );
+        result.relations = Relationship::from_json(artist)?;
+        Ok(result)
+    }
 }
 
 impl PartialEq for Artist {
@@ -58,131 +100,67 @@ impl fmt::Display for Artist {
     }
 }
 
-impl Entity for Artist {
-    fn search(&self, client: &super::MusicBrainz, params: &mut HashMap<&str, &str>) -> Result<Vec<Self>, Error> {
-        let data = match client.get("artist", params) {
-            Ok(x) => x,
-            Err(e) => return Err(Error::ParseJson(e))
-        };
+/// Subqueries that can be included in a lookup request.
+const LOOKUP_INCLUDES: &'static [Include] = &[
+    Include::Aliases, Include::Annotation, Include::Tags, Include::Genres, Include::UserTags,
+    Include::UserGenres, Include::Ratings, Include::UserRatings, Include::Recordings, Include::Releases,
+    Include::ReleaseGroups, Include::Works, Include::VariousArtists, Include::DiscIds, Include::Media,
+    Include::Isrcs
+];
 
-        let count = data["count"].as_i32().unwrap();
-        let mut results : Vec<Artist> = Vec::new();
+/// Subqueries that can be included in a browse request.
+const BROWSE_INCLUDES: &'static [Include] = &[
+    Include::Aliases, Include::Annotation, Include::Tags, Include::Genres, Include::UserTags,
+    Include::UserGenres, Include::Ratings, Include::UserRatings
+];
 
-        if count == 0 {
-            return Ok(results);
-        }
-
-        let artists = &data["artists"];
-
-        for artist in artists.members() {
-            if !artist["score"].is_null() {
-                if artist["score"] == "100" {
-                    if !artist["name"].is_null() {
-                        let name = artist["name"].to_string();
-                        let gender = artist["gender"].to_string();
-
-                        let id = match artist["id"].as_str() {
-                            Some(x) => {
-                                match Uuid::parse_str(x) {
-                                    Ok(y) => y,
-                                    Err(e) => return Err(Error::ParseUuid(e))
-                                }
-                            },
-                            None => return Err(Error::AsSlice)
-                        };
-
-                        let mut tags: Vec<String> = Vec::new();
-                        let release_groups: Vec<ReleaseGroup> = Vec::new();
-
-                        for tag in artist["tags"].members() {
-                            tags.push(tag["name"].to_string());
-                        }
-
-                        results.push(Artist::new(id, name, gender, PersonType::Other, tags, release_groups));
-                    }
-                }
-            }
-        }
-        Ok(results)
+impl Entity for Artist {
+    fn search(&self, client: &super::MusicBrainz, params: &mut HashMap<&str, &str>) -> Result<Page<SearchResult<Self>>, Error> {
+        client.search("artist", "artists", params, Artist::from_json)
     }
 
     fn lookup(&self, client: &super::MusicBrainz, entity_id: &Uuid, params: &mut HashMap<&str, &str>) -> Result<Self, Error> {
         let artist_data = match client.get(&format!("artist/{id}", id=entity_id), params) {
             Ok(x) => x,
-            Err(e) => return Err(Error::ParseJson(e))
+            Err(e) => return Err(e)
         };
 
-        if !artist_data["error"].is_null() {
-            let error_msg = artist_data["error"].to_string();
-            return Err(Error::Http(error_msg));
-        }
+        Artist::from_json(&artist_data)
+    }
 
-        let artist_type = match artist_data["type"].as_str() {
-            Some(x) => x.parse::<PersonType>().unwrap(),
-            None => return Err(Error::AsSlice)
-        };
+    fn search_all<'a>(&self, client: &'a super::MusicBrainz, params: &HashMap<&str, &str>) -> Paginated<'a, SearchResult<Self>> {
+        client.search_all("artist", "artists", params, Artist::from_json)
+    }
 
-        let mut tags: Vec<String> = Vec::new();
-        if !artist_data["tags"].is_null() {
-            for tag in artist_data["tags"].members() {
-                tags.push(tag["name"].to_string());
-            }
+    fn includes(&self, request: Request) -> &'static [Include] {
+        match request {
+            Request::Lookup => LOOKUP_INCLUDES,
+            Request::Browse => BROWSE_INCLUDES,
+            _ => &[]
         }
+    }
+}
 
-        let artist_id = match artist_data["id"].as_str() {
-            Some(x) => {
-                match Uuid::parse_str(x) {
-                    Ok(y) => y,
-                    Err(e) => return Err(Error::ParseUuid(e))
-                }
-            },
-            None => return Err(Error::AsSlice)
-        };
+#[cfg(feature = "async")]
+impl AsyncEntity for Artist {
+    fn search_async(&self, client: &AsyncMusicBrainz, params: &HashMap<&str, &str>) -> ApiFuture<Page<SearchResult<Self>>> {
+        client.search("artist", "artists", params, Artist::from_json)
+    }
 
-        let mut artist_albums: Vec<ReleaseGroup> = Vec::new();
-        if !artist_data["release-groups"].is_null() {
-            for album in artist_data["release-groups"].members() {
-                let mut secondary_types: Vec<AlbumType> = Vec::new();
-                for secondary_type in album["secondary-types"].members() {
-                    secondary_types.push(match secondary_type.as_str() {
-                        Some(x) => x.parse::<AlbumType>().unwrap(),
-                        None => return Err(Error::AsSlice)
-                    });
-                }
-
-                let album_id = match album["id"].as_str() {
-                    Some(x) => {
-                        match Uuid::parse_str(x) {
-                            Ok(y) => y,
-                            Err(e) => return Err(Error::ParseUuid(e))
-                        }
-                    },
-                    None => return Err(Error::AsSlice)
-                };
-
-                let album_type = match album["primary-type"].as_str() {
-                    Some(x) => x.parse::<AlbumType>().unwrap(),
-                    None => return Err(Error::AsSlice)
-                };
-
-                artist_albums.push(ReleaseGroup::new(
-                    album["title"].to_string(),
-                    album["first-release-date"].to_string(),
-                    album_id,
-                    artist_id,
-                    album_type,
-                    secondary_types
-                ));
-            }
-        }
+    fn lookup_async(&self, client: &AsyncMusicBrainz, entity_id: &Uuid, params: &HashMap<&str, &str>) -> ApiFuture<Self> {
+        client.lookup(&format!("artist/{id}", id=entity_id), params, Artist::from_json)
+    }
+}
 
-        Ok(Artist::new(
-            artist_id,
-            artist_data["name"].to_string(),
-            artist_data["gender"].to_string(),
-            artist_type,
-            tags,
-            artist_albums
-        ))
+/// Entities that can be linked to in a browse request.
+const BROWSE_BY: &'static [&'static str] = &["area", "collection", "recording", "release", "release-group", "work"];
+
+impl Browse for Artist {
+    fn browse(&self, client: &super::MusicBrainz, linked: &LinkedEntity, params: &mut HashMap<&str, &str>) -> Result<Page<Self>, Error> {
+        client.browse("artist", "artists", BROWSE_BY, linked, params, Artist::from_json)
+    }
+
+    fn browse_all<'a>(&self, client: &'a super::MusicBrainz, linked: &LinkedEntity, params: &HashMap<&str, &str>) -> Paginated<'a, Self> {
+        client.browse_all("artist", "artists", BROWSE_BY, linked, params, Artist::from_json)
     }
-}
\ No newline at end of file
+}
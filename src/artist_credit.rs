@@ -0,0 +1,107 @@
+use std::fmt;
+use artist::Artist;
+use error::Error;
+use parse;
+use json::JsonValue;
+
+/// How the artists of a recording, release, release group or track are credited.
+///
+/// Collaborations are represented as an ordered list of name credits, each followed
+/// by the text placed between it and the next one, so that `"Deadmau5 feat. Kaskade"`
+/// is made of the credits `Deadmau5` (joined by `" feat. "`) and `Kaskade`.
+///
+/// # Example
+///
+/// ```no_run
+/// # use musicbrainz::*;
+/// # use musicbrainz::include::Include;
+/// let musicbrainz = MusicBrainz::new();
+///
+/// let release_group_id = Uuid::parse_str("7e4e8d3b-5e2b-3ad5-9c9d-4b0a7f1b6f1c").expect("failed to parse release group ID as Uuid");
+/// let release_group = musicbrainz.release_group().lookup_with(&musicbrainz, &release_group_id, &[Include::ArtistCredits]).unwrap();
+///
+/// println!("{} by {}", release_group.title, release_group.artist_credit);
+/// for artist in release_group.artist_credit.artists() {
+///     println!("{} ({})", artist.name, artist.id);
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct ArtistCredit {
+    pub credits: Vec<NameCredit>
+}
+
+/// A single artist as credited in an `ArtistCredit`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub struct NameCredit {
+    /// The name the artist is credited as, which may differ from the name of the artist.
+    pub name: String,
+    /// The text placed between this credit and the next one, e.g. `" feat. "`.
+    #[cfg_attr(feature = "serde", serde(rename = "joinphrase", default, with = "::serde_impls::nullable"))]
+    pub join_phrase: String,
+    pub artist: Artist
+}
+
+impl ArtistCredit {
+    pub fn new(credits: Vec<NameCredit>) -> ArtistCredit {
+        ArtistCredit {
+            credits: credits
+        }
+    }
+
+    pub fn empty() -> ArtistCredit {
+        ArtistCredit::new(Vec::new())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.credits.is_empty()
+    }
+
+    /// The credited artists, in order.
+    pub fn artists(&self) -> Vec<&Artist> {
+        self.credits.iter().map(|x| &x.artist).collect()
+    }
+
+    /// Renders the credit as displayed by MusicBrainz, e.g. `Deadmau5 feat. Kaskade`.
+    pub fn display_name(&self) -> String {
+        let mut name = String::new();
+        for credit in &self.credits {
+            name.push_str(&credit.name);
+            name.push_str(&credit.join_phrase);
+        }
+        name
+    }
+
+    /// Parses the `artist-credit` of a web service response, which is absent unless
+    /// artist credits were requested.
+    pub fn from_json(data: &JsonValue) -> Result<ArtistCredit, Error> {
+        let credits = parse::list(data, "artist-credit", |credit| {
+            let artist = match Artist::from_json(&credit["artist"]) {
+                Ok(x) => x,
+                Err(e) => return Err(e.within("artist"))
+            };
+
+            let name = match credit["name"].as_str() {
+                Some(x) => x.to_string(),
+                None => artist.name.clone()
+            };
+
+            Ok(NameCredit {
+                name: name,
+                join_phrase: credit["joinphrase"].as_str().unwrap_or("").to_string(),
+                artist: artist
+            })
+        })?;
+
+        Ok(ArtistCredit::new(credits))
+    }
+}
+
+impl fmt::Display for ArtistCredit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name())
+    }
+}
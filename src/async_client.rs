@@ -0,0 +1,533 @@
+//! An asynchronous client, for running many requests concurrently without
+//! blocking a thread per request.
+//!
+//! Only available with the `async` feature. The futures returned here do not
+//! depend on a particular runtime: requests go through an `AsyncTransport`, which
+//! by default runs the blocking `HyperTransport` on a worker thread. Requests
+//! still go through the client's `RateLimiter` and `RetryPolicy`, and responses
+//! are parsed by the same code as the blocking client.
+//!
+//! # Example
+//!
+//! ```no_run
+//! # use musicbrainz::*;
+//! # use musicbrainz::async_client::AsyncMusicBrainz;
+//! # use std::collections::HashMap;
+//! let musicbrainz = AsyncMusicBrainz::new();
+//! let query = HashMap::new();
+//!
+//! let artist_id = Uuid::parse_str("4a00ec9d-c635-463a-8cd4-eb61725f0c60").expect("failed to parse artist ID as Uuid");
+//! let artist = musicbrainz.artist().lookup_async(&musicbrainz, &artist_id, &query);
+//! // `artist` can now be awaited, or spawned on any executor.
+//! ```
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+use std::error;
+use std::fmt;
+use std::future::{self, Future};
+use std::panic::{self, AssertUnwindSafe};
+use std::pin::Pin;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, OnceLock};
+use std::sync::mpsc::{self, Sender, Receiver};
+use std::task::{Context, Poll, Waker};
+use std::thread;
+use std::time::{Duration, Instant};
+use json::JsonValue;
+use browse::Page;
+use error::Error;
+use format::Format;
+use rate_limit::RateLimiter;
+use retry::RetryPolicy;
+use search::SearchResult;
+use transport::{Transport, HyperTransport, Request, Response};
+use {artist, release_group, release, recording, label, work, area, place, event, instrument, series, genre, url};
+
+/// The result of an asynchronous request.
+pub type ApiFuture<T> = Pin<Box<dyn Future<Output = Result<T, Error>> + Send>>;
+
+/// The response of an `AsyncTransport` to a request.
+pub type TransportFuture = Pin<Box<dyn Future<Output = Result<Response, Box<dyn error::Error + Send + Sync>>> + Send>>;
+
+/// Sends requests to the web service on behalf of `AsyncMusicBrainz`.
+///
+/// Implement this trait to use the HTTP client and timer of an async runtime.
+pub trait AsyncTransport: Send + Sync {
+    /// Sends `request`, resolving to an error only if no response could be obtained.
+    ///
+    /// As with `Transport`, HTTP error statuses must be returned as a `Response`.
+    fn send(&self, request: &Request) -> TransportFuture;
+
+    /// Returns a future completing after `duration`, used to wait for the rate
+    /// limiter and between retries.
+    ///
+    /// The default implementation relies on a single timer thread shared by every
+    /// client; transports built on a runtime should use its timer instead.
+    fn sleep(&self, duration: Duration) -> Pin<Box<dyn Future<Output = ()> + Send>> {
+        Box::pin(timer().sleep(duration))
+    }
+}
+
+type Job = Box<dyn FnOnce() + Send>;
+
+/// Runs a blocking `Transport` on a fixed number of worker threads, started with the
+/// transport and stopped once it is dropped.
+pub struct BlockingTransport {
+    inner: Arc<dyn Transport>,
+    jobs: Mutex<Sender<Job>>
+}
+
+impl BlockingTransport {
+    /// Sends the requests one at a time on a single worker thread, which is enough
+    /// to keep up with the rate limit of musicbrainz.org.
+    pub fn new(inner: Box<dyn Transport>) -> BlockingTransport {
+        BlockingTransport::with_threads(inner, 1)
+    }
+
+    /// Sends up to `threads` requests at once, e.g. against a mirror without rate limit.
+    pub fn with_threads(inner: Box<dyn Transport>, threads: usize) -> BlockingTransport {
+        BlockingTransport::from_arc(Arc::from(inner), threads)
+    }
+
+    fn from_arc(inner: Arc<dyn Transport>, threads: usize) -> BlockingTransport {
+        let (sender, receiver) = mpsc::channel::<Job>();
+        let receiver = Arc::new(Mutex::new(receiver));
+        for _ in 0..threads.max(1) {
+            let receiver = receiver.clone();
+            thread::spawn(move || work(&receiver));
+        }
+
+        BlockingTransport {
+            inner: inner,
+            jobs: Mutex::new(sender)
+        }
+    }
+}
+
+/// Runs the jobs sent to `receiver` until every sender is dropped.
+fn work(receiver: &Mutex<Receiver<Job>>) {
+    loop {
+        let job = match lock(receiver).recv() {
+            Ok(job) => job,
+            Err(_) => return
+        };
+        job();
+    }
+}
+
+impl AsyncTransport for BlockingTransport {
+    fn send(&self, request: &Request) -> TransportFuture {
+        let inner = self.inner.clone();
+        let request = request.clone();
+        let (pending, completion) = pending();
+
+        let job: Job = Box::new(move || {
+            let result = match panic::catch_unwind(AssertUnwindSafe(|| inner.send(&request))) {
+                Ok(result) => result,
+                Err(_) => Err(From::from("transport panicked while sending the request"))
+            };
+            completion.complete(result);
+        });
+
+        match lock(&self.jobs).send(job) {
+            Ok(()) => Box::pin(pending),
+            Err(_) => Box::pin(future::ready(Err(From::from("the worker threads of the transport have stopped"))))
+        }
+    }
+}
+
+/// The asynchronous counterpart of `MusicBrainz`.
+///
+/// Cloning a client is cheap; clones share the same transport and rate limiter.
+#[derive(Clone)]
+pub struct AsyncMusicBrainz {
+    transport: Arc<dyn AsyncTransport>,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    retry_policy: RetryPolicy,
+    base_url: String,
+    user_agent: String,
+    format: Format,
+    min_score: u8
+}
+
+impl AsyncMusicBrainz {
+    /// Instantiates a new client, sending its requests with a `HyperTransport`
+    /// run on a worker thread.
+    pub fn new() -> AsyncMusicBrainz {
+        AsyncMusicBrainz::with_transport(Box::new(BlockingTransport::new(Box::new(HyperTransport::new()))))
+    }
+
+    /// Instantiates a new client sending its requests through `transport`.
+    ///
+    /// Use `MusicBrainzBuilder::build_async_with` to also configure the other settings.
+    pub fn with_transport(transport: Box<dyn AsyncTransport>) -> AsyncMusicBrainz {
+        AsyncMusicBrainz {
+            transport: Arc::from(transport),
+            rate_limiter: RateLimiter::default_for(super::BASE_URI).map(Arc::new),
+            retry_policy: RetryPolicy::default(),
+            base_url: super::BASE_URI.to_string(),
+            user_agent: super::builder::library_user_agent(),
+            format: Format::Json,
+            min_score: 0
+        }
+    }
+
+    /// Creates a client with the same settings as `client`.
+    ///
+    /// The transport and rate limiter are shared, so requests sent by both clients
+    /// count towards the same rate limit.
+    pub fn from_client(client: &super::MusicBrainz) -> AsyncMusicBrainz {
+        AsyncMusicBrainz {
+            transport: Arc::new(BlockingTransport::from_arc(client.transport.clone(), 1)),
+            rate_limiter: client.rate_limiter.clone(),
+            retry_policy: client.retry_policy.clone(),
+            base_url: client.base_url.clone(),
+            user_agent: client.user_agent.clone(),
+            format: client.format,
+            min_score: client.min_score
+        }
+    }
+
+    /// Replaces the transport, keeping the other settings.
+    pub fn set_transport(&mut self, transport: Box<dyn AsyncTransport>) {
+        self.transport = Arc::from(transport);
+    }
+
+    /// Sets the minimum score search hits must have to be returned.
+    pub fn set_min_score(&mut self, min_score: u8) {
+        self.min_score = min_score;
+    }
+
+    pub fn min_score(&self) -> u8 {
+        self.min_score
+    }
+
+    /// Sets the rate limiter requests go through, or disables rate limiting with `None`.
+    pub fn set_rate_limit(&mut self, rate_limiter: Option<RateLimiter>) {
+        self.rate_limiter = rate_limiter.map(Arc::new);
+    }
+
+    /// Sets how requests are retried when MusicBrainz is unavailable or throttling the client.
+    pub fn set_retry_policy(&mut self, retry_policy: RetryPolicy) {
+        self.retry_policy = retry_policy;
+    }
+
+    /// Sets the format responses are requested in.
+    pub fn set_format(&mut self, format: Format) {
+        self.format = format;
+    }
+
+    pub fn format(&self) -> Format {
+        self.format
+    }
+
+    fn get(&self, url: &str, params: &HashMap<&str, &str>) -> Result<Get, Error> {
+        Ok(Get {
+            client: self.clone(),
+            request: super::build_request(&self.base_url, &self.user_agent, self.format, url, params)?,
+            attempt: 1,
+            state: State::Ready
+        })
+    }
+
+    /// Issues a lookup request for `url`, shared by every `AsyncEntity` implementation.
+    pub(crate) fn lookup<T: Send + 'static>(&self, url: &str, params: &HashMap<&str, &str>,
+                                            parse: fn(&JsonValue) -> Result<T, Error>) -> ApiFuture<T> {
+        match self.get(url, params) {
+            Ok(get) => Box::pin(Parse { get: get, parse: Box::new(parse) }),
+            Err(e) => Box::pin(future::ready(Err(e)))
+        }
+    }
+
+    /// Issues a search request against `endpoint`, shared by every `AsyncEntity` implementation.
+    ///
+    /// `key` is the plural name under which the hits are returned (e.g. `artists`).
+    pub(crate) fn search<T: Send + 'static>(&self, endpoint: &str, key: &str, params: &HashMap<&str, &str>,
+                                            parse: fn(&JsonValue) -> Result<T, Error>) -> ApiFuture<Page<SearchResult<T>>> {
+        let key = key.to_string();
+        let min_score = self.min_score;
+
+        match self.get(endpoint, params) {
+            Ok(get) => Box::pin(Parse {
+                get: get,
+                parse: Box::new(move |data| super::search_page(data, &key, min_score, parse))
+            }),
+            Err(e) => Box::pin(future::ready(Err(e)))
+        }
+    }
+
+    pub fn artist(&self) -> artist::Artist {
+        artist::Artist::empty()
+    }
+
+    pub fn release_group(&self) -> release_group::ReleaseGroup {
+        release_group::ReleaseGroup::empty()
+    }
+
+    pub fn release(&self) -> release::Release {
+        release::Release::empty()
+    }
+
+    pub fn recording(&self) -> recording::Recording {
+        recording::Recording::empty()
+    }
+
+    pub fn label(&self) -> label::Label {
+        label::Label::empty()
+    }
+
+    pub fn work(&self) -> work::Work {
+        work::Work::empty()
+    }
+
+    pub fn area(&self) -> area::Area {
+        area::Area::empty()
+    }
+
+    pub fn place(&self) -> place::Place {
+        place::Place::empty()
+    }
+
+    pub fn event(&self) -> event::Event {
+        event::Event::empty()
+    }
+
+    pub fn instrument(&self) -> instrument::Instrument {
+        instrument::Instrument::empty()
+    }
+
+    pub fn series(&self) -> series::Series {
+        series::Series::empty()
+    }
+
+    pub fn genre(&self) -> genre::Genre {
+        genre::Genre::empty()
+    }
+
+    pub fn url(&self) -> url::Url {
+        url::Url::empty()
+    }
+}
+
+impl fmt::Debug for AsyncMusicBrainz {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AsyncMusicBrainz")
+            .field("base_url", &self.base_url)
+            .field("user_agent", &self.user_agent)
+            .field("rate_limiter", &self.rate_limiter)
+            .field("retry_policy", &self.retry_policy)
+            .field("format", &self.format)
+            .field("min_score", &self.min_score)
+            .finish()
+    }
+}
+
+enum State {
+    /// Waiting for the rate limiter before sending the request.
+    Ready,
+    Waiting(Pin<Box<dyn Future<Output = ()> + Send>>),
+    Sending(TransportFuture)
+}
+
+/// Sends a request, retrying it as allowed by the retry policy, and resolves to its body read as JSON.
+struct Get {
+    client: AsyncMusicBrainz,
+    request: Request,
+    attempt: u32,
+    state: State
+}
+
+impl Future for Get {
+    type Output = Result<JsonValue, Error>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Result<JsonValue, Error>> {
+        let this = self.get_mut();
+        loop {
+            let next = match this.state {
+                State::Ready => {
+                    let wait = match this.client.rate_limiter {
+                        Some(ref rate_limiter) => rate_limiter.try_acquire().err(),
+                        None => None
+                    };
+
+                    match wait {
+                        Some(delay) => State::Waiting(this.client.transport.sleep(delay)),
+                        None => State::Sending(this.client.transport.send(&this.request))
+                    }
+                },
+                State::Waiting(ref mut delay) => match delay.as_mut().poll(cx) {
+                    Poll::Ready(()) => State::Ready,
+                    Poll::Pending => return Poll::Pending
+                },
+                State::Sending(ref mut response) => match response.as_mut().poll(cx) {
+                    Poll::Ready(Ok(res)) => match super::check_response(&this.client.retry_policy, this.client.format, this.attempt, &res) {
+                        super::Attempt::Done(result) => return Poll::Ready(result),
+                        super::Attempt::Retry(delay) => {
+                            this.attempt += 1;
+                            State::Waiting(this.client.transport.sleep(delay))
+                        }
+                    },
+                    Poll::Ready(Err(e)) => return Poll::Ready(Err(super::transport_error(e))),
+                    Poll::Pending => return Poll::Pending
+                }
+            };
+            this.state = next;
+        }
+    }
+}
+
+/// Parses the JSON body a `Get` resolves to.
+struct Parse<T> {
+    get: Get,
+    parse: Box<dyn Fn(&JsonValue) -> Result<T, Error> + Send>
+}
+
+impl<T> Future for Parse<T> {
+    type Output = Result<T, Error>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Result<T, Error>> {
+        let this = self.get_mut();
+        match Pin::new(&mut this.get).poll(cx) {
+            Poll::Ready(Ok(data)) => Poll::Ready((this.parse)(&data)),
+            Poll::Ready(Err(e)) => Poll::Ready(Err(e)),
+            Poll::Pending => Poll::Pending
+        }
+    }
+}
+
+struct Shared<T> {
+    result: Option<T>,
+    waker: Option<Waker>
+}
+
+/// Resolves to the value passed to the matching `Completion`.
+struct Pending<T> {
+    shared: Arc<Mutex<Shared<T>>>
+}
+
+/// Completes a `Pending` future from another thread.
+struct Completion<T> {
+    shared: Arc<Mutex<Shared<T>>>
+}
+
+fn pending<T>() -> (Pending<T>, Completion<T>) {
+    let shared = Arc::new(Mutex::new(Shared { result: None, waker: None }));
+    (Pending { shared: shared.clone() }, Completion { shared: shared })
+}
+
+impl<T> Completion<T> {
+    fn complete(&self, result: T) {
+        let mut shared = lock(&self.shared);
+        shared.result = Some(result);
+        if let Some(waker) = shared.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+impl<T> Future for Pending<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<T> {
+        let mut shared = lock(&self.shared);
+        match shared.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                shared.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+/// A sleep registered with the `Timer`, ordered so that the earliest deadline is
+/// at the top of the heap.
+struct Deadline {
+    at: Instant,
+    completion: Completion<()>
+}
+
+impl PartialEq for Deadline {
+    fn eq(&self, other: &Deadline) -> bool {
+        self.at == other.at
+    }
+}
+
+impl Eq for Deadline {}
+
+impl PartialOrd for Deadline {
+    fn partial_cmp(&self, other: &Deadline) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Deadline {
+    fn cmp(&self, other: &Deadline) -> Ordering {
+        other.at.cmp(&self.at)
+    }
+}
+
+/// Completes every sleep of the default `AsyncTransport::sleep` from a single thread.
+struct Timer {
+    deadlines: Mutex<BinaryHeap<Deadline>>,
+    changed: Condvar
+}
+
+/// The timer shared by every client, started on first use.
+fn timer() -> &'static Timer {
+    static TIMER: OnceLock<Timer> = OnceLock::new();
+
+    let mut started = false;
+    let timer = TIMER.get_or_init(|| {
+        started = true;
+        Timer { deadlines: Mutex::new(BinaryHeap::new()), changed: Condvar::new() }
+    });
+    if started {
+        thread::spawn(move || timer.run());
+    }
+    timer
+}
+
+impl Timer {
+    fn sleep(&self, duration: Duration) -> Pending<()> {
+        let (pending, completion) = pending();
+        if duration == Duration::from_secs(0) {
+            completion.complete(());
+        } else {
+            lock(&self.deadlines).push(Deadline { at: Instant::now() + duration, completion: completion });
+            self.changed.notify_one();
+        }
+        pending
+    }
+
+    fn run(&self) {
+        let mut deadlines = lock(&self.deadlines);
+        loop {
+            let now = Instant::now();
+            while deadlines.peek().map_or(false, |x| x.at <= now) {
+                if let Some(deadline) = deadlines.pop() {
+                    deadline.completion.complete(());
+                }
+            }
+
+            let wait = deadlines.peek().map(|x| x.at - now);
+            deadlines = match wait {
+                Some(timeout) => match self.changed.wait_timeout(deadlines, timeout) {
+                    Ok((guard, _)) => guard,
+                    Err(poisoned) => poisoned.into_inner().0
+                },
+                None => match self.changed.wait(deadlines) {
+                    Ok(guard) => guard,
+                    Err(poisoned) => poisoned.into_inner()
+                }
+            };
+        }
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    match mutex.lock() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner()
+    }
+}
@@ -0,0 +1,75 @@
+use uuid::Uuid;
+use std::fmt;
+
+/// An entity that other entities can be browsed by.
+///
+/// For example, browsing release groups by `LinkedEntity::Artist` returns every
+/// release group credited to that artist, which is not capped to 25 items like
+/// `inc=release-groups` is on artist lookups.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LinkedEntity {
+    Area(Uuid),
+    Artist(Uuid),
+    Collection(Uuid),
+    Event(Uuid),
+    Label(Uuid),
+    Place(Uuid),
+    Recording(Uuid),
+    Release(Uuid),
+    ReleaseGroup(Uuid),
+    /// Releases containing a track credited to the artist.
+    TrackArtist(Uuid),
+    Work(Uuid)
+}
+
+impl LinkedEntity {
+    /// Name of the query parameter used to browse by this entity.
+    pub fn name(&self) -> &'static str {
+        match *self {
+            LinkedEntity::Area(_) => "area",
+            LinkedEntity::Artist(_) => "artist",
+            LinkedEntity::Collection(_) => "collection",
+            LinkedEntity::Event(_) => "event",
+            LinkedEntity::Label(_) => "label",
+            LinkedEntity::Place(_) => "place",
+            LinkedEntity::Recording(_) => "recording",
+            LinkedEntity::Release(_) => "release",
+            LinkedEntity::ReleaseGroup(_) => "release-group",
+            LinkedEntity::TrackArtist(_) => "track_artist",
+            LinkedEntity::Work(_) => "work"
+        }
+    }
+
+    pub fn id(&self) -> &Uuid {
+        match *self {
+            LinkedEntity::Area(ref id) |
+            LinkedEntity::Artist(ref id) |
+            LinkedEntity::Collection(ref id) |
+            LinkedEntity::Event(ref id) |
+            LinkedEntity::Label(ref id) |
+            LinkedEntity::Place(ref id) |
+            LinkedEntity::Recording(ref id) |
+            LinkedEntity::Release(ref id) |
+            LinkedEntity::ReleaseGroup(ref id) |
+            LinkedEntity::TrackArtist(ref id) |
+            LinkedEntity::Work(ref id) => id
+        }
+    }
+}
+
+impl fmt::Display for LinkedEntity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{name}={id}", name=self.name(), id=self.id())
+    }
+}
+
+/// A single page of results returned by a browse or search request.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Page<T> {
+    /// Index of the first item of this page among all results.
+    pub offset: usize,
+    /// Total number of results, across all pages.
+    pub count: usize,
+    pub items: Vec<T>
+}
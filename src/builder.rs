@@ -0,0 +1,218 @@
+use std::borrow::Cow;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+use hyper;
+use liburl::Url;
+use error::Error;
+use transport::{Transport, HyperTransport};
+use rate_limit::RateLimiter;
+use retry::RetryPolicy;
+use format::Format;
+#[cfg(feature = "async")]
+use async_client::{AsyncMusicBrainz, AsyncTransport};
+
+/// Configures and creates a `MusicBrainz` client.
+///
+/// MusicBrainz asks every application to identify itself with a meaningful
+/// User-Agent, made of its name, version and a way to contact its author.
+///
+/// # Example
+///
+/// ```no_run
+/// # use musicbrainz::*;
+/// # use std::time::Duration;
+/// let musicbrainz = MusicBrainz::builder()
+///     .base_url("http://localhost:5000/ws/2")
+///     .application("my-tagger", "1.2.0", "https://example.org/my-tagger")
+///     .timeout(Duration::from_secs(10))
+///     .build()
+///     .expect("invalid client configuration");
+/// ```
+pub struct MusicBrainzBuilder {
+    base_url: String,
+    application: Option<(String, String, String)>,
+    read_timeout: Option<Duration>,
+    write_timeout: Option<Duration>,
+    proxy: Option<(String, u16)>,
+    transport: Option<Box<dyn Transport>>,
+    rate_limiter: Option<Option<RateLimiter>>,
+    retry_policy: RetryPolicy,
+    format: Format,
+    min_score: u8
+}
+
+impl MusicBrainzBuilder {
+    pub fn new() -> MusicBrainzBuilder {
+        MusicBrainzBuilder {
+            base_url: super::BASE_URI.to_string(),
+            application: None,
+            read_timeout: None,
+            write_timeout: None,
+            proxy: None,
+            transport: None,
+            rate_limiter: None,
+            retry_policy: RetryPolicy::default(),
+            format: Format::Json,
+            min_score: 0
+        }
+    }
+
+    /// Sends requests to the web service at `base_url`, such as a local mirror,
+    /// instead of `https://musicbrainz.org/ws/2`.
+    pub fn base_url(mut self, base_url: &str) -> MusicBrainzBuilder {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Identifies the application in the User-Agent of every request, as
+    /// `name/version ( contact )`. `contact` is a URL or an email address.
+    pub fn application(mut self, name: &str, version: &str, contact: &str) -> MusicBrainzBuilder {
+        self.application = Some((name.to_string(), version.to_string(), contact.to_string()));
+        self
+    }
+
+    /// Sets both the read and write timeouts of the default transport.
+    pub fn timeout(self, timeout: Duration) -> MusicBrainzBuilder {
+        self.read_timeout(timeout).write_timeout(timeout)
+    }
+
+    pub fn read_timeout(mut self, timeout: Duration) -> MusicBrainzBuilder {
+        self.read_timeout = Some(timeout);
+        self
+    }
+
+    pub fn write_timeout(mut self, timeout: Duration) -> MusicBrainzBuilder {
+        self.write_timeout = Some(timeout);
+        self
+    }
+
+    /// Routes the requests of the default transport through the HTTP proxy at `host:port`.
+    pub fn proxy(mut self, host: &str, port: u16) -> MusicBrainzBuilder {
+        self.proxy = Some((host.to_string(), port));
+        self
+    }
+
+    /// Sends requests through `transport` instead of a `HyperTransport`.
+    ///
+    /// Timeouts and proxy settings only apply to the default transport, and are
+    /// ignored once a custom transport is set.
+    pub fn transport(mut self, transport: Box<dyn Transport>) -> MusicBrainzBuilder {
+        self.transport = Some(transport);
+        self
+    }
+
+    /// Sets the rate limiter requests go through, or disables rate limiting with `None`.
+    ///
+    /// Defaults to `RateLimiter::default_for` the base URL.
+    pub fn rate_limit(mut self, rate_limiter: Option<RateLimiter>) -> MusicBrainzBuilder {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> MusicBrainzBuilder {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Sets the format responses are requested in. Defaults to `Format::Json`.
+    pub fn format(mut self, format: Format) -> MusicBrainzBuilder {
+        self.format = format;
+        self
+    }
+
+    pub fn min_score(mut self, min_score: u8) -> MusicBrainzBuilder {
+        self.min_score = min_score;
+        self
+    }
+
+    /// Creates the client, failing if the base URL is not valid.
+    pub fn build(self) -> Result<super::MusicBrainz, Error> {
+        if let Err(e) = Url::parse(&self.base_url) {
+            return Err(Error::InvalidUrl(e));
+        }
+
+        let user_agent = match self.application {
+            Some((ref name, ref version, ref contact)) => format!("{name}/{version} ( {contact} ) {library}",
+                name=name, version=version, contact=contact, library=library_user_agent()
+            ),
+            None => library_user_agent()
+        };
+
+        let transport = match self.transport {
+            Some(transport) => transport,
+            None => {
+                let mut client = match self.proxy {
+                    Some((host, port)) => hyper::Client::with_http_proxy(Cow::Owned(host), port),
+                    None => hyper::Client::new()
+                };
+                client.set_read_timeout(self.read_timeout);
+                client.set_write_timeout(self.write_timeout);
+                Box::new(HyperTransport::from_client(client))
+            }
+        };
+
+        let rate_limiter = match self.rate_limiter {
+            Some(rate_limiter) => rate_limiter,
+            None => RateLimiter::default_for(&self.base_url)
+        };
+
+        Ok(super::MusicBrainz {
+            transport: Arc::from(transport),
+            rate_limiter: rate_limiter.map(Arc::new),
+            retry_policy: self.retry_policy,
+            base_url: self.base_url,
+            user_agent: user_agent,
+            format: self.format,
+            min_score: self.min_score
+        })
+    }
+}
+
+#[cfg(feature = "async")]
+impl MusicBrainzBuilder {
+    /// Creates an asynchronous client, running the configured transport on separate threads.
+    pub fn build_async(self) -> Result<AsyncMusicBrainz, Error> {
+        let client = self.build()?;
+        Ok(AsyncMusicBrainz::from_client(&client))
+    }
+
+    /// Creates an asynchronous client sending its requests through `transport`.
+    ///
+    /// Timeouts and proxy settings are ignored, as with `transport`.
+    pub fn build_async_with(self, transport: Box<dyn AsyncTransport>) -> Result<AsyncMusicBrainz, Error> {
+        let mut client = self.build_async()?;
+        client.set_transport(transport);
+        Ok(client)
+    }
+}
+
+impl Default for MusicBrainzBuilder {
+    fn default() -> MusicBrainzBuilder {
+        MusicBrainzBuilder::new()
+    }
+}
+
+impl fmt::Debug for MusicBrainzBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MusicBrainzBuilder")
+            .field("base_url", &self.base_url)
+            .field("application", &self.application)
+            .field("read_timeout", &self.read_timeout)
+            .field("write_timeout", &self.write_timeout)
+            .field("proxy", &self.proxy)
+            .field("rate_limiter", &self.rate_limiter)
+            .field("retry_policy", &self.retry_policy)
+            .field("format", &self.format)
+            .field("min_score", &self.min_score)
+            .finish()
+    }
+}
+
+/// Identifies this library, e.g. `musicbrainz/0.1.0 ( https://github.com/RockyTV/musicbrainz.rs )`.
+pub fn library_user_agent() -> String {
+    format!("{name}/{version} ( {repository} )",
+        name=env!("CARGO_PKG_NAME"), version=env!("CARGO_PKG_VERSION"),
+        repository=env!("CARGO_PKG_REPOSITORY")
+    )
+}
@@ -1,7 +1,11 @@
 use std::str::FromStr;
 use std::fmt;
+#[cfg(feature = "serde")]
+use serde::{Serialize, Serializer, Deserialize, Deserializer};
+#[cfg(feature = "serde")]
+use serde::de::Error;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 /// The PersonType enum is used to state whether an artist is a person, a group, or something else.
 pub enum PersonType {
     /// Indicates an individual person.
@@ -34,6 +38,12 @@ impl FromStr for PersonType {
     }
 }
 
+impl Default for PersonType {
+    fn default() -> PersonType {
+        PersonType::Other
+    }
+}
+
 impl fmt::Display for PersonType {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
@@ -47,7 +57,7 @@ impl fmt::Display for PersonType {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum AlbumType {
     Album,
     Single,
@@ -62,6 +72,9 @@ pub enum AlbumType {
     Remix,
     DjMix,
     MixtapeStreet,
+    Demo,
+    AudioDrama,
+    FieldRecording,
     Other,
 }
 
@@ -83,12 +96,21 @@ impl FromStr for AlbumType {
             "Remix" => Ok(AlbumType::Remix),
             "DJ-mix" => Ok(AlbumType::DjMix),
             "Mixtape/Street" => Ok(AlbumType::MixtapeStreet),
+            "Demo" => Ok(AlbumType::Demo),
+            "Audio drama" => Ok(AlbumType::AudioDrama),
+            "Field recording" => Ok(AlbumType::FieldRecording),
             "Other" => Ok(AlbumType::Other),
             _ => Err(())
         }
     }
 }
 
+impl Default for AlbumType {
+    fn default() -> AlbumType {
+        AlbumType::Other
+    }
+}
+
 impl fmt::Display for AlbumType {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
@@ -105,7 +127,182 @@ impl fmt::Display for AlbumType {
             AlbumType::Remix => write!(f, "Remix"),
             AlbumType::DjMix => write!(f, "DJ-mix"),
             AlbumType::MixtapeStreet => write!(f, "Mixtape/Street"),
+            AlbumType::Demo => write!(f, "Demo"),
+            AlbumType::AudioDrama => write!(f, "Audio drama"),
+            AlbumType::FieldRecording => write!(f, "Field recording"),
             AlbumType::Other => write!(f, "Other")
         }
     }
-}
\ No newline at end of file
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// The ReleaseStatus enum describes how "official" a release is.
+pub enum ReleaseStatus {
+    /// Any release officially sanctioned by the artist and/or their record company.
+    Official,
+    /// A give-away release or a release intended to promote an upcoming official release.
+    Promotion,
+    /// An unofficial/underground release that was not sanctioned by the artist and/or the record company.
+    Bootleg,
+    /// An alternate version of a release where the titles have been changed.
+    PseudoRelease,
+    /// A release that was withdrawn from circulation by the artist and/or their record company.
+    Withdrawn,
+    /// A release that was withdrawn and whose distribution the artist wishes to be erased.
+    Expunged,
+    /// A release that was planned but never released.
+    Cancelled,
+}
+
+impl FromStr for ReleaseStatus {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<ReleaseStatus, ()> {
+        match s {
+            "Official" => Ok(ReleaseStatus::Official),
+            "Promotion" => Ok(ReleaseStatus::Promotion),
+            "Bootleg" => Ok(ReleaseStatus::Bootleg),
+            "Pseudo-Release" => Ok(ReleaseStatus::PseudoRelease),
+            "Withdrawn" => Ok(ReleaseStatus::Withdrawn),
+            "Expunged" => Ok(ReleaseStatus::Expunged),
+            "Cancelled" => Ok(ReleaseStatus::Cancelled),
+            _ => Err(())
+        }
+    }
+}
+
+impl fmt::Display for ReleaseStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ReleaseStatus::Official => write!(f, "Official"),
+            ReleaseStatus::Promotion => write!(f, "Promotion"),
+            ReleaseStatus::Bootleg => write!(f, "Bootleg"),
+            ReleaseStatus::PseudoRelease => write!(f, "Pseudo-Release"),
+            ReleaseStatus::Withdrawn => write!(f, "Withdrawn"),
+            ReleaseStatus::Expunged => write!(f, "Expunged"),
+            ReleaseStatus::Cancelled => write!(f, "Cancelled")
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, PartialEq)]
+/// The LabelType enum describes the main activity of a label.
+pub enum LabelType {
+    /// A label name used for marketing purposes only, without any other activity.
+    Imprint,
+    /// A label whose main activity is producing new releases.
+    OriginalProduction,
+    /// A label which produces unofficial releases.
+    BootlegProduction,
+    /// A label which reissues older material.
+    ReissueProduction,
+    /// A company that distributes releases.
+    Distributor,
+    /// A company owning other labels, usually without releasing anything itself.
+    Holding,
+    /// An organization collecting royalties on behalf of artists.
+    RightsSociety,
+    /// A company that manages rights on musical works.
+    Publisher,
+    /// A company that physically manufactures releases.
+    Manufacturer,
+    /// Anything which does not fit into the above categories.
+    Other
+}
+
+impl FromStr for LabelType {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<LabelType, ()> {
+        match s {
+            "Imprint" => Ok(LabelType::Imprint),
+            "Original Production" => Ok(LabelType::OriginalProduction),
+            "Bootleg Production" => Ok(LabelType::BootlegProduction),
+            "Reissue Production" => Ok(LabelType::ReissueProduction),
+            "Distributor" => Ok(LabelType::Distributor),
+            "Holding" => Ok(LabelType::Holding),
+            "Rights Society" => Ok(LabelType::RightsSociety),
+            "Publisher" => Ok(LabelType::Publisher),
+            "Manufacturer" => Ok(LabelType::Manufacturer),
+            "Other" => Ok(LabelType::Other),
+            _ => Err(())
+        }
+    }
+}
+
+impl fmt::Display for LabelType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            LabelType::Imprint => write!(f, "Imprint"),
+            LabelType::OriginalProduction => write!(f, "Original Production"),
+            LabelType::BootlegProduction => write!(f, "Bootleg Production"),
+            LabelType::ReissueProduction => write!(f, "Reissue Production"),
+            LabelType::Distributor => write!(f, "Distributor"),
+            LabelType::Holding => write!(f, "Holding"),
+            LabelType::RightsSociety => write!(f, "Rights Society"),
+            LabelType::Publisher => write!(f, "Publisher"),
+            LabelType::Manufacturer => write!(f, "Manufacturer"),
+            LabelType::Other => write!(f, "Other")
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// The Direction enum tells which side of a relationship the entity it was read from is on.
+pub enum Direction {
+    /// The entity is the source of the relationship, e.g. the artist a producer relationship starts from.
+    Forward,
+    /// The entity is the target of the relationship.
+    Backward
+}
+
+impl FromStr for Direction {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Direction, ()> {
+        match s {
+            "forward" => Ok(Direction::Forward),
+            "backward" => Ok(Direction::Backward),
+            _ => Err(())
+        }
+    }
+}
+
+impl Default for Direction {
+    fn default() -> Direction {
+        Direction::Forward
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Direction::Forward => write!(f, "forward"),
+            Direction::Backward => write!(f, "backward")
+        }
+    }
+}
+
+/// Implements `Serialize` and `Deserialize` using the strings of the web service,
+/// as given by `Display` and `FromStr`.
+#[cfg(feature = "serde")]
+macro_rules! serde_str {
+    ($($name:ident),*) => {$(
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_str(self)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<$name, D::Error> {
+                let value = String::deserialize(deserializer)?;
+                value.parse::<$name>().map_err(|_| D::Error::custom(format!("unknown {} '{}'", stringify!($name), value)))
+            }
+        }
+    )*}
+}
+
+#[cfg(feature = "serde")]
+serde_str!(PersonType, AlbumType, ReleaseStatus, LabelType, Direction);
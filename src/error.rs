@@ -1,22 +1,167 @@
-use std::{fmt, error};
-use json;
+use std::{fmt, error, io};
+use json::{self, JsonValue};
 use uuid;
+use liburl;
+use retry;
+#[cfg(feature = "xml")]
+use roxmltree;
 
+/// The error returned by MusicBrainz along with an unsuccessful HTTP status.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiError {
+    pub status: u16,
+    /// The message of the `error` field, e.g. `Not Found`.
+    pub error: String,
+    /// The `help` field, pointing to the relevant documentation, if any.
+    pub help: Option<String>
+}
+
+impl ApiError {
+    /// Reads the error body of a response, which is not JSON when the error
+    /// comes from a proxy in front of the web service.
+    pub fn from_body(status: u16, body: &str) -> ApiError {
+        let data = json::parse(body).unwrap_or(JsonValue::Null);
+        #[cfg(feature = "xml")]
+        let data = if data.is_null() { ::xml::to_json(body).unwrap_or(JsonValue::Null) } else { data };
+
+        let error = match data["error"].as_str() {
+            Some(x) => x.to_string(),
+            None => body.trim().to_string()
+        };
+
+        ApiError {
+            status: status,
+            error: error,
+            help: data["help"].as_str().map(|x| x.to_string())
+        }
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "status {}", self.status)?;
+        if !self.error.is_empty() {
+            write!(f, ": {}", self.error)?;
+        }
+        if let Some(ref help) = self.help {
+            write!(f, " ({})", help)?;
+        }
+        Ok(())
+    }
+}
+
+/// The errors returned by requests to the web service.
+///
+/// Errors about the content of a response carry the JSON path of the offending
+/// field, e.g. `release-groups[2].id`.
+///
+/// # Example
+///
+/// ```no_run
+/// # use musicbrainz::*;
+/// # use musicbrainz::error::Error;
+/// # use std::collections::HashMap;
+/// let musicbrainz = MusicBrainz::new();
+///
+/// let artist_id = Uuid::parse_str("00000000-0000-0000-0000-000000000000").unwrap();
+/// match musicbrainz.artist().lookup(&musicbrainz, &artist_id, &mut HashMap::new()) {
+///     Ok(artist) => println!("{}", artist),
+///     Err(Error::NotFound(_)) => println!("no such artist"),
+///     Err(e) => println!("request failed: {}", e)
+/// }
+/// ```
 #[derive(Debug)]
 pub enum Error {
-    Http(String),
+    /// The request could not be sent, or its response could not be received.
+    Transport(Box<dyn error::Error + Send + Sync>),
+    Io(io::Error),
+    InvalidUrl(liburl::ParseError),
+    /// The entity does not exist (`404`).
+    NotFound(ApiError),
+    /// The request was rejected, e.g. because of an invalid parameter (`400`).
+    BadRequest(ApiError),
+    /// MusicBrainz was still unavailable or throttling the client after every attempt
+    /// allowed by the `RetryPolicy`: a `429` or `503`, or any other `5xx` that was retried.
+    Throttled { error: ApiError, attempts: u32 },
+    /// Any other unsuccessful status.
+    Status(ApiError),
     ParseJson(json::Error),
-    ParseUuid(uuid::ParseError),
-    AsSlice
+    /// The response is not a valid XML document. Only raised with the `xml` feature.
+    #[cfg(feature = "xml")]
+    ParseXml(roxmltree::Error),
+    /// A required field is absent from the response.
+    MissingField(String),
+    /// A field does not have the expected JSON type.
+    InvalidType { path: String, expected: &'static str },
+    /// A field is not a valid MusicBrainz identifier.
+    InvalidMbid { path: String, source: uuid::ParseError },
+    /// A field holds a value this library does not know about, such as a new album type.
+    UnknownValue { path: String, value: String },
+    Unsupported(String)
+}
+
+impl Error {
+    /// Builds the error matching an unsuccessful response.
+    pub fn from_status(status: u16, body: &str, attempts: u32) -> Error {
+        let error = ApiError::from_body(status, body);
+        match status {
+            400 => Error::BadRequest(error),
+            404 => Error::NotFound(error),
+            429 | 503 => Error::Throttled { error: error, attempts: attempts },
+            // Other statuses are only reported as such once they have been retried.
+            _ if attempts > 1 && retry::is_retryable(status) => Error::Throttled { error: error, attempts: attempts },
+            _ => Error::Status(error)
+        }
+    }
+
+    /// The HTTP status of the response, for errors caused by an unsuccessful status.
+    pub fn status(&self) -> Option<u16> {
+        match *self {
+            Error::NotFound(ref e) | Error::BadRequest(ref e) | Error::Status(ref e) => Some(e.status),
+            Error::Throttled { ref error, .. } => Some(error.status),
+            _ => None
+        }
+    }
+
+    /// Prefixes the path of a field error with `parent`, so that an error about
+    /// `id` raised while parsing the third release group reads `release-groups[2].id`.
+    pub fn within(self, parent: &str) -> Error {
+        let prefix = |path: String| if path.is_empty() {
+            parent.to_string()
+        } else if path.starts_with('[') {
+            format!("{}{}", parent, path)
+        } else {
+            format!("{}.{}", parent, path)
+        };
+
+        match self {
+            Error::MissingField(path) => Error::MissingField(prefix(path)),
+            Error::InvalidType { path, expected } => Error::InvalidType { path: prefix(path), expected: expected },
+            Error::InvalidMbid { path, source } => Error::InvalidMbid { path: prefix(path), source: source },
+            Error::UnknownValue { path, value } => Error::UnknownValue { path: prefix(path), value: value },
+            other => other
+        }
+    }
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
-            Error::Http(ref s) => write!(f, "error requesting resource: {}", s),
+            Error::Transport(ref e) => write!(f, "error sending request: {}", e),
+            Error::Io(ref e) => write!(f, "i/o error: {}", e),
+            Error::InvalidUrl(ref e) => write!(f, "error parsing url: {}", e),
+            Error::NotFound(ref e) => write!(f, "not found: {}", e),
+            Error::BadRequest(ref e) => write!(f, "bad request: {}", e),
+            Error::Throttled { ref error, attempts } => write!(f, "server unavailable after {} attempts: {}", attempts, error),
+            Error::Status(ref e) => write!(f, "error requesting resource: {}", e),
             Error::ParseJson(ref e) => write!(f, "error parsing json: {}", e),
-            Error::ParseUuid(ref e) => write!(f, "error parsing uuid: {}", e),
-            Error::AsSlice => write!(f, "error parsing as slice"),
+            #[cfg(feature = "xml")]
+            Error::ParseXml(ref e) => write!(f, "error parsing xml: {}", e),
+            Error::MissingField(ref path) => write!(f, "missing field '{}'", path),
+            Error::InvalidType { ref path, expected } => write!(f, "field '{}' is not a {}", path, expected),
+            Error::InvalidMbid { ref path, ref source } => write!(f, "field '{}' is not a valid MBID: {}", path, source),
+            Error::UnknownValue { ref path, ref value } => write!(f, "unknown value '{}' for field '{}'", value, path),
+            Error::Unsupported(ref s) => write!(f, "unsupported request: {}", s),
         }
     }
 }
@@ -24,10 +169,34 @@ impl fmt::Display for Error {
 impl error::Error for Error {
     fn description(&self) -> &str {
         match *self {
-            Error::Http(_) => "error requesting resource",
+            Error::Transport(_) => "error sending request",
+            Error::Io(_) => "i/o error",
+            Error::InvalidUrl(_) => "error parsing url",
+            Error::NotFound(_) => "not found",
+            Error::BadRequest(_) => "bad request",
+            Error::Throttled { .. } => "server unavailable after retrying",
+            Error::Status(_) => "error requesting resource",
             Error::ParseJson(ref err) => err.description(),
-            Error::ParseUuid(_) => "error parsing uuid",
-            Error::AsSlice => "error parsing as slice"
+            #[cfg(feature = "xml")]
+            Error::ParseXml(_) => "error parsing xml",
+            Error::MissingField(_) => "missing field",
+            Error::InvalidType { .. } => "field of unexpected type",
+            Error::InvalidMbid { .. } => "invalid mbid",
+            Error::UnknownValue { .. } => "unknown value",
+            Error::Unsupported(_) => "unsupported request"
         }
     }
-}
\ No newline at end of file
+
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            Error::Transport(ref e) => Some(&**e),
+            Error::Io(ref e) => Some(e),
+            Error::InvalidUrl(ref e) => Some(e),
+            Error::ParseJson(ref e) => Some(e),
+            #[cfg(feature = "xml")]
+            Error::ParseXml(ref e) => Some(e),
+            Error::InvalidMbid { ref source, .. } => Some(source),
+            _ => None
+        }
+    }
+}
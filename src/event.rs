@@ -0,0 +1,167 @@
+use uuid::Uuid;
+use life_span::LifeSpan;
+use std::collections::HashMap;
+use std::fmt;
+use traits::{Entity, Browse};
+use browse::{LinkedEntity, Page};
+use error::Error;
+use parse;
+use include::{Include, Request};
+use paginated::Paginated;
+use search::SearchResult;
+use json::JsonValue;
+use relationship::Relationship;
+#[cfg(feature = "async")]
+use traits::AsyncEntity;
+#[cfg(feature = "async")]
+use async_client::{AsyncMusicBrainz, ApiFuture};
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub struct Event {
+    #[cfg_attr(feature = "serde", serde(with = "::serde_impls::uuid"))]
+    pub id: Uuid,
+    pub name: String,
+    #[cfg_attr(feature = "serde", serde(rename = "type", default, with = "::serde_impls::nullable"))]
+    pub event_type: String,
+    #[cfg_attr(feature = "serde", serde(default, with = "::serde_impls::nullable"))]
+    pub disambiguation: String,
+    /// Start time of the event, formatted as `HH:MM`.
+    #[cfg_attr(feature = "serde", serde(default, with = "::serde_impls::nullable"))]
+    pub time: String,
+    #[cfg_attr(feature = "serde", serde(default, with = "::serde_impls::nullable"))]
+    pub cancelled: bool,
+    #[cfg_attr(feature = "serde", serde(default, with = "::serde_impls::nullable"))]
+    pub setlist: String,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub life_span: LifeSpan,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub relations: Vec<Relationship>
+}
+
+impl Event {
+    pub fn new(id: Uuid, name: String, event_type: String, disambiguation: String, time: String, cancelled: bool,
+               setlist: String, life_span: LifeSpan) -> Event {
+        Event {
+            id: id,
+            name: name,
+            event_type: event_type,
+            disambiguation: disambiguation,
+            time: time,
+            cancelled: cancelled,
+            setlist: setlist,
+            life_span: life_span,
+            relations: Vec::new()
+        }
+    }
+
+    pub fn empty() -> Event {
+        Event::new(
+            Uuid::nil(),
+            String::new(),
+            String::new(),
+            String::new(),
+            String::new(),
+            false,
+            String::new(),
+            LifeSpan::empty()
+        )
+    }
+
+    pub(crate) fn from_json(event: &JsonValue) -> Result<Event, Error> {
+        let id = parse::mbid(event, "id")?;
+
+        let mut result = Event::new(
+            id,
+            parse::string(event, "name")?,
+            event["type"].as_str().unwrap_or("").to_string(),
+            event["disambiguation"].as_str().unwrap_or("").to_string(),
+            event["time"].as_str().unwrap_or("").to_string(),
+            event["cancelled"].as_bool().unwrap_or(false),
+            event["setlist"].as_str().unwrap_or("").to_string(),
+            match LifeSpan::from_json(&event["life-span"]) {
+                Ok(x) => x,
+                Err(e) => return Err(e.within("life-span"))
+            }
+        );
+        result.relations = Relationship::from_json(event)?;
+        Ok(result)
+    }
+}
+
+impl PartialEq for Event {
+    fn eq(&self, other: &Event) -> bool {
+        self.id == other.id && self.name == other.name
+    }
+}
+
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{name} ({type})", name=self.name, type=self.event_type)?;
+        writeln!(f, "Id: {id}", id=self.id)
+    }
+}
+
+/// Subqueries that can be included in a lookup request.
+const LOOKUP_INCLUDES: &'static [Include] = &[
+    Include::Aliases, Include::Annotation, Include::Tags, Include::Genres, Include::UserTags,
+    Include::UserGenres, Include::Ratings, Include::UserRatings
+];
+
+/// Subqueries that can be included in a browse request.
+const BROWSE_INCLUDES: &'static [Include] = &[
+    Include::Aliases, Include::Annotation, Include::Tags, Include::Genres, Include::UserTags,
+    Include::UserGenres, Include::Ratings, Include::UserRatings
+];
+
+impl Entity for Event {
+    fn search(&self, client: &super::MusicBrainz, params: &mut HashMap<&str, &str>) -> Result<Page<SearchResult<Self>>, Error> {
+        client.search("event", "events", params, Event::from_json)
+    }
+
+    fn lookup(&self, client: &super::MusicBrainz, entity_id: &Uuid, params: &mut HashMap<&str, &str>) -> Result<Self, Error> {
+        let event_data = match client.get(&format!("event/{id}", id=entity_id), params) {
+            Ok(x) => x,
+            Err(e) => return Err(e)
+        };
+
+        Event::from_json(&event_data)
+    }
+
+    fn search_all<'a>(&self, client: &'a super::MusicBrainz, params: &HashMap<&str, &str>) -> Paginated<'a, SearchResult<Self>> {
+        client.search_all("event", "events", params, Event::from_json)
+    }
+
+    fn includes(&self, request: Request) -> &'static [Include] {
+        match request {
+            Request::Lookup => LOOKUP_INCLUDES,
+            Request::Browse => BROWSE_INCLUDES,
+            _ => &[]
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncEntity for Event {
+    fn search_async(&self, client: &AsyncMusicBrainz, params: &HashMap<&str, &str>) -> ApiFuture<Page<SearchResult<Self>>> {
+        client.search("event", "events", params, Event::from_json)
+    }
+
+    fn lookup_async(&self, client: &AsyncMusicBrainz, entity_id: &Uuid, params: &HashMap<&str, &str>) -> ApiFuture<Self> {
+        client.lookup(&format!("event/{id}", id=entity_id), params, Event::from_json)
+    }
+}
+
+/// Entities that can be linked to in a browse request.
+const BROWSE_BY: &'static [&'static str] = &["area", "artist", "collection", "place"];
+
+impl Browse for Event {
+    fn browse(&self, client: &super::MusicBrainz, linked: &LinkedEntity, params: &mut HashMap<&str, &str>) -> Result<Page<Self>, Error> {
+        client.browse("event", "events", BROWSE_BY, linked, params, Event::from_json)
+    }
+
+    fn browse_all<'a>(&self, client: &'a super::MusicBrainz, linked: &LinkedEntity, params: &HashMap<&str, &str>) -> Paginated<'a, Self> {
+        client.browse_all("event", "events", BROWSE_BY, linked, params, Event::from_json)
+    }
+}
@@ -0,0 +1,156 @@
+//! A `Transport` serving recorded responses, for testing without network access.
+//!
+//! Only available with the `testing` feature.
+
+use std::error;
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use liburl::Url;
+use transport::{Transport, Request, Response};
+
+/// Serves responses recorded in a directory, keyed by request path and query.
+///
+/// The response to `GET /ws/2/artist/<mbid>?fmt=json&inc=tags` is read from
+/// `<dir>/artist/<mbid>/fmt=json&inc=tags.json`, and responses requested with
+/// `fmt=xml` from a `.xml` file. Query parameters are sorted so the order in
+/// which they were added does not matter, and the characters that are not safe
+/// in file names are percent-encoded, e.g. `query=artist%3Adeadmau5`. Responses
+/// with a status other than `200` also have a `.status` file holding the status code.
+///
+/// # Example
+///
+/// ```no_run
+/// # use musicbrainz::*;
+/// # use musicbrainz::fixture::FixtureTransport;
+/// # use musicbrainz::transport::HyperTransport;
+/// // Record the responses of the live web service once...
+/// let recorder = FixtureTransport::record("tests/fixtures", Box::new(HyperTransport::new()));
+/// let musicbrainz = MusicBrainz::with_transport(Box::new(recorder));
+///
+/// // ...then replay them in tests.
+/// let musicbrainz = MusicBrainz::with_transport(Box::new(FixtureTransport::replay("tests/fixtures")));
+/// ```
+pub struct FixtureTransport {
+    dir: PathBuf,
+    recorder: Option<Box<dyn Transport>>
+}
+
+impl FixtureTransport {
+    /// Serves the responses recorded in `dir`, failing on requests that were never recorded.
+    pub fn replay<P: AsRef<Path>>(dir: P) -> FixtureTransport {
+        FixtureTransport {
+            dir: dir.as_ref().to_path_buf(),
+            recorder: None
+        }
+    }
+
+    /// Sends requests through `inner`, saving every response in `dir`.
+    pub fn record<P: AsRef<Path>>(dir: P, inner: Box<dyn Transport>) -> FixtureTransport {
+        FixtureTransport {
+            dir: dir.as_ref().to_path_buf(),
+            recorder: Some(inner)
+        }
+    }
+
+    /// Path of the fixture holding the response to `request`, without extension.
+    pub fn fixture_path(&self, request: &Request) -> Result<PathBuf, Box<dyn error::Error + Send + Sync>> {
+        let url = Url::parse(&request.url)?;
+
+        let mut path = self.dir.clone();
+        let segments = url.path().trim_start_matches("/ws/2/").trim_matches('/');
+        for segment in segments.split('/') {
+            path.push(sanitize(segment));
+        }
+
+        let mut query: Vec<String> = url.query_pairs()
+            .map(|(param, val)| format!("{}={}", sanitize(&param), sanitize(&val)))
+            .collect();
+        query.sort();
+
+        if query.is_empty() {
+            path.push("index");
+        } else {
+            path.push(query.join("&"));
+        }
+        Ok(path)
+    }
+
+    fn save(&self, path: &Path, extension: &str, response: &Response) -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        File::create(with_suffix(path, extension))?.write_all(response.body.as_bytes())?;
+
+        // A successful retry must not be replayed with the status of the failed attempt.
+        let status = with_suffix(path, ".status");
+        if response.status != 200 {
+            File::create(status)?.write_all(response.status.to_string().as_bytes())?;
+        } else if status.exists() {
+            fs::remove_file(status)?;
+        }
+        Ok(())
+    }
+
+    fn load(&self, path: &Path, extension: &str) -> io::Result<Response> {
+        let mut body = String::new();
+        File::open(with_suffix(path, extension))?.read_to_string(&mut body)?;
+
+        let status = match File::open(with_suffix(path, ".status")) {
+            Ok(mut file) => {
+                let mut code = String::new();
+                file.read_to_string(&mut code)?;
+                code.trim().parse::<u16>().map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?
+            },
+            Err(_) => 200
+        };
+
+        Ok(Response {
+            status: status,
+            headers: Vec::new(),
+            body: body
+        })
+    }
+}
+
+impl Transport for FixtureTransport {
+    fn send(&self, request: &Request) -> Result<Response, Box<dyn error::Error + Send + Sync>> {
+        let path = self.fixture_path(request)?;
+        let extension = if request.url.contains("fmt=xml") { ".xml" } else { ".json" };
+
+        match self.recorder {
+            Some(ref inner) => {
+                let response = inner.send(request)?;
+                self.save(&path, extension, &response)?;
+                Ok(response)
+            },
+            None => match self.load(&path, extension) {
+                Ok(response) => Ok(response),
+                Err(e) => Err(From::from(format!("no fixture recorded for '{}' ({}): {}", request.url, path.display(), e)))
+            }
+        }
+    }
+}
+
+/// Percent-encodes the bytes that are not safe in file names, so that distinct
+/// queries are always saved to distinct fixtures.
+fn sanitize(s: &str) -> String {
+    let mut result = String::new();
+    for byte in s.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-_.+".contains(&byte) {
+            result.push(byte as char);
+        } else {
+            result.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    result
+}
+
+/// Appends `suffix` to the file name of `path`. Unlike `Path::with_extension`, this
+/// keeps dots that are part of the query (e.g. `query=mr.oizo`).
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut path = path.as_os_str().to_os_string();
+    path.push(suffix);
+    PathBuf::from(path)
+}
@@ -0,0 +1,54 @@
+//! The formats responses can be requested in.
+
+use json::{self, JsonValue};
+use error::Error;
+
+/// The format MusicBrainz is asked to answer in.
+///
+/// Both formats are read into the same entities; the XML Metadata format is
+/// mostly useful with mirrors running older versions of the server.
+///
+/// # Example
+///
+/// ```no_run
+/// # use musicbrainz::*;
+/// # use musicbrainz::format::Format;
+/// let mut musicbrainz = MusicBrainz::new();
+/// musicbrainz.set_format(Format::Json);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Json,
+    /// The MusicBrainz XML Metadata format (MMD). Only available with the `xml` feature.
+    #[cfg(feature = "xml")]
+    Xml
+}
+
+impl Format {
+    /// The value of the `fmt` query parameter requesting this format.
+    pub fn param(&self) -> &'static str {
+        match *self {
+            Format::Json => "json",
+            #[cfg(feature = "xml")]
+            Format::Xml => "xml"
+        }
+    }
+
+    /// Reads a response body into the JSON structure the entities are parsed from.
+    pub(crate) fn parse(&self, body: &str) -> Result<JsonValue, Error> {
+        match *self {
+            Format::Json => match json::parse(body) {
+                Ok(x) => Ok(x),
+                Err(e) => Err(Error::ParseJson(e))
+            },
+            #[cfg(feature = "xml")]
+            Format::Xml => ::xml::to_json(body)
+        }
+    }
+}
+
+impl Default for Format {
+    fn default() -> Format {
+        Format::Json
+    }
+}
@@ -0,0 +1,130 @@
+use uuid::Uuid;
+use std::collections::HashMap;
+use std::fmt;
+use traits::Entity;
+use error::Error;
+use parse;
+use include::{Include, Request};
+use paginated::Paginated;
+use browse::Page;
+use search::SearchResult;
+use json::JsonValue;
+use relationship::Relationship;
+#[cfg(feature = "async")]
+use traits::AsyncEntity;
+#[cfg(feature = "async")]
+use async_client::{AsyncMusicBrainz, ApiFuture};
+#[cfg(feature = "async")]
+use std::future;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub struct Genre {
+    #[cfg_attr(feature = "serde", serde(with = "::serde_impls::uuid"))]
+    pub id: Uuid,
+    pub name: String,
+    #[cfg_attr(feature = "serde", serde(default, with = "::serde_impls::nullable"))]
+    pub disambiguation: String,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub relations: Vec<Relationship>
+}
+
+impl Genre {
+    pub fn new(id: Uuid, name: String, disambiguation: String) -> Genre {
+        Genre {
+            id: id,
+            name: name,
+            disambiguation: disambiguation,
+            relations: Vec::new()
+        }
+    }
+
+    pub fn empty() -> Genre {
+        Genre::new(Uuid::nil(), String::new(), String::new())
+    }
+
+    /// Lists every genre known to MusicBrainz.
+    ///
+    /// The `genre/all` endpoint is paginated, so this issues as many requests as
+    /// needed to retrieve the whole list.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use musicbrainz::*;
+    /// let musicbrainz = MusicBrainz::new();
+    /// let genres = genre::Genre::all(&musicbrainz).unwrap();
+    ///
+    /// assert!(genres.iter().any(|genre| genre.name == "electronic"));
+    /// ```
+    pub fn all(client: &super::MusicBrainz) -> Result<Vec<Genre>, Error> {
+        Paginated::new(client, "genre/all", "genres", "genre-count", &HashMap::new(), Genre::from_json).collect()
+    }
+
+    pub(crate) fn from_json(genre: &JsonValue) -> Result<Genre, Error> {
+        let id = parse::mbid(genre, "id")?;
+
+        let mut result = Genre::new(
+            id,
+            parse::string(genre, "name")?,
+            genre["disambiguation"].as_str().unwrap_or("").to_string()
+        );
+        result.relations = Relationship::from_json(genre)?;
+        Ok(result)
+    }
+}
+
+impl PartialEq for Genre {
+    fn eq(&self, other: &Genre) -> bool {
+        self.id == other.id && self.name == other.name
+    }
+}
+
+impl fmt::Display for Genre {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{name}", name=self.name)?;
+        writeln!(f, "Id: {id}", id=self.id)
+    }
+}
+
+/// Subqueries that can be included in a lookup request.
+const LOOKUP_INCLUDES: &'static [Include] = &[];
+
+impl Entity for Genre {
+    /// Genres cannot be searched; use `Genre::all` to list them instead.
+    fn search(&self, _client: &super::MusicBrainz, _params: &mut HashMap<&str, &str>) -> Result<Page<SearchResult<Self>>, Error> {
+        Err(Error::Unsupported(String::from("genres cannot be searched")))
+    }
+
+    fn lookup(&self, client: &super::MusicBrainz, entity_id: &Uuid, params: &mut HashMap<&str, &str>) -> Result<Self, Error> {
+        let genre_data = match client.get(&format!("genre/{id}", id=entity_id), params) {
+            Ok(x) => x,
+            Err(e) => return Err(e)
+        };
+
+        Genre::from_json(&genre_data)
+    }
+
+    fn search_all<'a>(&self, client: &'a super::MusicBrainz, _params: &HashMap<&str, &str>) -> Paginated<'a, SearchResult<Self>> {
+        Paginated::failed(client, Error::Unsupported(String::from("genres cannot be searched")))
+    }
+
+    fn includes(&self, request: Request) -> &'static [Include] {
+        match request {
+            Request::Lookup => LOOKUP_INCLUDES,
+            _ => &[]
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncEntity for Genre {
+    fn search_async(&self, _client: &AsyncMusicBrainz, _params: &HashMap<&str, &str>) -> ApiFuture<Page<SearchResult<Self>>> {
+        Box::pin(future::ready(Err(Error::Unsupported(String::from("genres cannot be searched")))))
+    }
+
+    fn lookup_async(&self, client: &AsyncMusicBrainz, entity_id: &Uuid, params: &HashMap<&str, &str>) -> ApiFuture<Self> {
+        client.lookup(&format!("genre/{id}", id=entity_id), params, Genre::from_json)
+    }
+}
@@ -0,0 +1,153 @@
+use std::fmt;
+use error::Error;
+
+/// A subquery requesting additional data through the `inc` parameter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Include {
+    Aliases,
+    Annotation,
+    Tags,
+    Ratings,
+    Genres,
+    /// Tags submitted by the authenticated user.
+    UserTags,
+    /// Ratings submitted by the authenticated user.
+    UserRatings,
+    /// Genres submitted by the authenticated user.
+    UserGenres,
+    ArtistCredits,
+    Artists,
+    Collections,
+    /// Collections of the authenticated user.
+    UserCollections,
+    DiscIds,
+    Isrcs,
+    Labels,
+    Media,
+    Recordings,
+    ReleaseGroups,
+    Releases,
+    /// Releases on which the artist only appears on some tracks (artist lookups only).
+    VariousArtists,
+    Works,
+    AreaRels,
+    ArtistRels,
+    EventRels,
+    GenreRels,
+    InstrumentRels,
+    LabelRels,
+    PlaceRels,
+    RecordingRels,
+    ReleaseRels,
+    ReleaseGroupRels,
+    SeriesRels,
+    UrlRels,
+    WorkRels,
+    /// Relationships of every recording of a release.
+    RecordingLevelRels,
+    /// Relationships of the release group of a release.
+    ReleaseGroupLevelRels,
+    /// Relationships of every work linked to the recordings of a release.
+    WorkLevelRels
+}
+
+impl Include {
+    /// Whether this include requests relationships to a given entity type, which
+    /// every lookup and browse request supports.
+    pub fn is_relationship(&self) -> bool {
+        match *self {
+            Include::AreaRels |
+            Include::ArtistRels |
+            Include::EventRels |
+            Include::GenreRels |
+            Include::InstrumentRels |
+            Include::LabelRels |
+            Include::PlaceRels |
+            Include::RecordingRels |
+            Include::ReleaseRels |
+            Include::ReleaseGroupRels |
+            Include::SeriesRels |
+            Include::UrlRels |
+            Include::WorkRels => true,
+            _ => false
+        }
+    }
+}
+
+impl fmt::Display for Include {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Include::Aliases => write!(f, "aliases"),
+            Include::Annotation => write!(f, "annotation"),
+            Include::Tags => write!(f, "tags"),
+            Include::Ratings => write!(f, "ratings"),
+            Include::Genres => write!(f, "genres"),
+            Include::UserTags => write!(f, "user-tags"),
+            Include::UserRatings => write!(f, "user-ratings"),
+            Include::UserGenres => write!(f, "user-genres"),
+            Include::ArtistCredits => write!(f, "artist-credits"),
+            Include::Artists => write!(f, "artists"),
+            Include::Collections => write!(f, "collections"),
+            Include::UserCollections => write!(f, "user-collections"),
+            Include::DiscIds => write!(f, "discids"),
+            Include::Isrcs => write!(f, "isrcs"),
+            Include::Labels => write!(f, "labels"),
+            Include::Media => write!(f, "media"),
+            Include::Recordings => write!(f, "recordings"),
+            Include::ReleaseGroups => write!(f, "release-groups"),
+            Include::Releases => write!(f, "releases"),
+            Include::VariousArtists => write!(f, "various-artists"),
+            Include::Works => write!(f, "works"),
+            Include::AreaRels => write!(f, "area-rels"),
+            Include::ArtistRels => write!(f, "artist-rels"),
+            Include::EventRels => write!(f, "event-rels"),
+            Include::GenreRels => write!(f, "genre-rels"),
+            Include::InstrumentRels => write!(f, "instrument-rels"),
+            Include::LabelRels => write!(f, "label-rels"),
+            Include::PlaceRels => write!(f, "place-rels"),
+            Include::RecordingRels => write!(f, "recording-rels"),
+            Include::ReleaseRels => write!(f, "release-rels"),
+            Include::ReleaseGroupRels => write!(f, "release-group-rels"),
+            Include::SeriesRels => write!(f, "series-rels"),
+            Include::UrlRels => write!(f, "url-rels"),
+            Include::WorkRels => write!(f, "work-rels"),
+            Include::RecordingLevelRels => write!(f, "recording-level-rels"),
+            Include::ReleaseGroupLevelRels => write!(f, "release-group-level-rels"),
+            Include::WorkLevelRels => write!(f, "work-level-rels")
+        }
+    }
+}
+
+/// The kind of request includes are sent with, as each supports a different set of them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Request {
+    Lookup,
+    Browse,
+    Search
+}
+
+/// Validates `includes` against the ones `allowed` for a request, and joins them
+/// into the value of the `inc` parameter, e.g. `tags+release-groups`.
+///
+/// Relationship includes are always allowed, except for searches which do not
+/// support any include.
+pub fn to_param(request: Request, allowed: &[Include], includes: &[Include]) -> Result<String, Error> {
+    let mut names: Vec<String> = Vec::new();
+
+    for include in includes {
+        let legal = match request {
+            Request::Search => false,
+            _ => include.is_relationship() || allowed.contains(include)
+        };
+
+        if !legal {
+            return Err(Error::Unsupported(format!("'{}' cannot be included in this request", include)));
+        }
+
+        let name = include.to_string();
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+    Ok(names.join("+"))
+}
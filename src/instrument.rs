@@ -0,0 +1,146 @@
+use uuid::Uuid;
+use std::collections::HashMap;
+use std::fmt;
+use traits::{Entity, Browse};
+use browse::{LinkedEntity, Page};
+use error::Error;
+use parse;
+use include::{Include, Request};
+use paginated::Paginated;
+use search::SearchResult;
+use json::JsonValue;
+use relationship::Relationship;
+#[cfg(feature = "async")]
+use traits::AsyncEntity;
+#[cfg(feature = "async")]
+use async_client::{AsyncMusicBrainz, ApiFuture};
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub struct Instrument {
+    #[cfg_attr(feature = "serde", serde(with = "::serde_impls::uuid"))]
+    pub id: Uuid,
+    pub name: String,
+    #[cfg_attr(feature = "serde", serde(rename = "type", default, with = "::serde_impls::nullable"))]
+    pub instrument_type: String,
+    #[cfg_attr(feature = "serde", serde(default, with = "::serde_impls::nullable"))]
+    pub description: String,
+    #[cfg_attr(feature = "serde", serde(default, with = "::serde_impls::nullable"))]
+    pub disambiguation: String,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub relations: Vec<Relationship>
+}
+
+impl Instrument {
+    pub fn new(id: Uuid, name: String, instrument_type: String, description: String, disambiguation: String) -> Instrument {
+        Instrument {
+            id: id,
+            name: name,
+            instrument_type: instrument_type,
+            description: description,
+            disambiguation: disambiguation,
+            relations: Vec::new()
+        }
+    }
+
+    pub fn empty() -> Instrument {
+        Instrument::new(
+            Uuid::nil(),
+            String::new(),
+            String::new(),
+            String::new(),
+            String::new()
+        )
+    }
+
+    pub(crate) fn from_json(instrument: &JsonValue) -> Result<Instrument, Error> {
+        let id = parse::mbid(instrument, "id")?;
+
+        let mut result = Instrument::new(
+            id,
+            parse::string(instrument, "name")?,
+            instrument["type"].as_str().unwrap_or("").to_string(),
+            instrument["description"].as_str().unwrap_or("").to_string(),
+            instrument["disambiguation"].as_str().unwrap_or("").to_string()
+        );
+        result.relations = Relationship::from_json(instrument)?;
+        Ok(result)
+    }
+}
+
+impl PartialEq for Instrument {
+    fn eq(&self, other: &Instrument) -> bool {
+        self.id == other.id && self.name == other.name
+    }
+}
+
+impl fmt::Display for Instrument {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{name} ({type})", name=self.name, type=self.instrument_type)?;
+        writeln!(f, "Id: {id}", id=self.id)
+    }
+}
+
+/// Subqueries that can be included in a lookup request.
+const LOOKUP_INCLUDES: &'static [Include] = &[
+    Include::Aliases, Include::Annotation, Include::Tags, Include::Genres, Include::UserTags,
+    Include::UserGenres
+];
+
+/// Subqueries that can be included in a browse request.
+const BROWSE_INCLUDES: &'static [Include] = &[
+    Include::Aliases, Include::Annotation, Include::Tags, Include::Genres, Include::UserTags,
+    Include::UserGenres
+];
+
+impl Entity for Instrument {
+    fn search(&self, client: &super::MusicBrainz, params: &mut HashMap<&str, &str>) -> Result<Page<SearchResult<Self>>, Error> {
+        client.search("instrument", "instruments", params, Instrument::from_json)
+    }
+
+    fn lookup(&self, client: &super::MusicBrainz, entity_id: &Uuid, params: &mut HashMap<&str, &str>) -> Result<Self, Error> {
+        let instrument_data = match client.get(&format!("instrument/{id}", id=entity_id), params) {
+            Ok(x) => x,
+            Err(e) => return Err(e)
+        };
+
+        Instrument::from_json(&instrument_data)
+    }
+
+    fn search_all<'a>(&self, client: &'a super::MusicBrainz, params: &HashMap<&str, &str>) -> Paginated<'a, SearchResult<Self>> {
+        client.search_all("instrument", "instruments", params, Instrument::from_json)
+    }
+
+    fn includes(&self, request: Request) -> &'static [Include] {
+        match request {
+            Request::Lookup => LOOKUP_INCLUDES,
+            Request::Browse => BROWSE_INCLUDES,
+            _ => &[]
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncEntity for Instrument {
+    fn search_async(&self, client: &AsyncMusicBrainz, params: &HashMap<&str, &str>) -> ApiFuture<Page<SearchResult<Self>>> {
+        client.search("instrument", "instruments", params, Instrument::from_json)
+    }
+
+    fn lookup_async(&self, client: &AsyncMusicBrainz, entity_id: &Uuid, params: &HashMap<&str, &str>) -> ApiFuture<Self> {
+        client.lookup(&format!("instrument/{id}", id=entity_id), params, Instrument::from_json)
+    }
+}
+
+/// Entities that can be linked to in a browse request.
+const BROWSE_BY: &'static [&'static str] = &["collection"];
+
+impl Browse for Instrument {
+    fn browse(&self, client: &super::MusicBrainz, linked: &LinkedEntity, params: &mut HashMap<&str, &str>) -> Result<Page<Self>, Error> {
+        client.browse("instrument", "instruments", BROWSE_BY, linked, params, Instrument::from_json)
+    }
+
+    fn browse_all<'a>(&self, client: &'a super::MusicBrainz, linked: &LinkedEntity, params: &HashMap<&str, &str>) -> Paginated<'a, Self> {
+        client.browse_all("instrument", "instruments", BROWSE_BY, linked, params, Instrument::from_json)
+    }
+}
@@ -0,0 +1,199 @@
+use uuid::Uuid;
+use enums::LabelType;
+use life_span::LifeSpan;
+use std::collections::HashMap;
+use std::fmt;
+use traits::{Entity, Browse};
+use browse::{LinkedEntity, Page};
+use error::Error;
+use parse;
+use include::{Include, Request};
+use paginated::Paginated;
+use search::SearchResult;
+use json::JsonValue;
+use relationship::Relationship;
+#[cfg(feature = "async")]
+use traits::AsyncEntity;
+#[cfg(feature = "async")]
+use async_client::{AsyncMusicBrainz, ApiFuture};
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub struct Label {
+    #[cfg_attr(feature = "serde", serde(with = "::serde_impls::uuid"))]
+    pub id: Uuid,
+    pub name: String,
+    #[cfg_attr(feature = "serde", serde(default, with = "::serde_impls::nullable"))]
+    pub sort_name: String,
+    #[cfg_attr(feature = "serde", serde(default, with = "::serde_impls::nullable"))]
+    pub disambiguation: String,
+    /// The "LC" code of the label, without the `LC` prefix.
+    pub label_code: Option<u32>,
+    #[cfg_attr(feature = "serde", serde(rename = "type", default, with = "::serde_impls::known"))]
+    pub label_type: Option<LabelType>,
+    #[cfg_attr(feature = "serde", serde(default, with = "::serde_impls::nullable"))]
+    pub country: String,
+    #[cfg_attr(feature = "serde", serde(default, with = "::serde_impls::name"))]
+    pub area: String,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub life_span: LifeSpan,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub ipis: Vec<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub isnis: Vec<String>,
+    #[cfg_attr(feature = "serde", serde(default, with = "::serde_impls::names"))]
+    pub aliases: Vec<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub relations: Vec<Relationship>
+}
+
+impl Label {
+    pub fn new(id: Uuid, name: String, sort_name: String, disambiguation: String, label_code: Option<u32>,
+               label_type: Option<LabelType>, country: String, area: String, life_span: LifeSpan,
+               ipis: Vec<String>, isnis: Vec<String>, aliases: Vec<String>) -> Label {
+        Label {
+            id: id,
+            name: name,
+            sort_name: sort_name,
+            disambiguation: disambiguation,
+            label_code: label_code,
+            label_type: label_type,
+            country: country,
+            area: area,
+            life_span: life_span,
+            ipis: ipis,
+            isnis: isnis,
+            aliases: aliases,
+            relations: Vec::new()
+        }
+    }
+
+    pub fn empty() -> Label {
+        Label::new(
+            Uuid::nil(),
+            String::new(),
+            String::new(),
+            String::new(),
+            None,
+            None,
+            String::new(),
+            String::new(),
+            LifeSpan::empty(),
+            Vec::new(),
+            Vec::new(),
+            Vec::new()
+        )
+    }
+
+    pub(crate) fn from_json(label: &JsonValue) -> Result<Label, Error> {
+        let id = parse::mbid(label, "id")?;
+
+        let label_type = parse::optional::<LabelType>(label, "type")?;
+
+        let ipis = parse::strings(label, "ipis")?;
+        let isnis = parse::strings(label, "isnis")?;
+
+        let aliases = parse::list(label, "aliases", |alias| parse::string(alias, "name"))?;
+
+        let mut result = Label::new(
+            id,
+            parse::string(label, "name")?,
+            label["sort-name"].as_str().unwrap_or("").to_string(),
+            label["disambiguation"].as_str().unwrap_or("").to_string(),
+            label["label-code"].as_u32(),
+            label_type,
+            label["country"].as_str().unwrap_or("").to_string(),
+            label["area"]["name"].as_str().unwrap_or("").to_string(),
+            match LifeSpan::from_json(&label["life-span"]) {
+                Ok(x) => x,
+                Err(e) => return Err(e.within("life-span"))
+            },
+            ipis,
+            isnis,
+            aliases
+        );
+        result.relations = Relationship::from_json(label)?;
+        Ok(result)
+    }
+}
+
+impl PartialEq for Label {
+    fn eq(&self, other: &Label) -> bool {
+        self.id == other.id && self.name == other.name
+    }
+}
+
+impl fmt::Display for Label {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.label_code {
+            Some(code) => writeln!(f, "{name} (LC {code:05})", name=self.name, code=code)?,
+            None => writeln!(f, "{name}", name=self.name)?
+        }
+        writeln!(f, "Id: {id}", id=self.id)
+    }
+}
+
+/// Subqueries that can be included in a lookup request.
+const LOOKUP_INCLUDES: &'static [Include] = &[
+    Include::Aliases, Include::Annotation, Include::Tags, Include::Genres, Include::UserTags,
+    Include::UserGenres, Include::Ratings, Include::UserRatings, Include::Releases, Include::DiscIds,
+    Include::Media
+];
+
+/// Subqueries that can be included in a browse request.
+const BROWSE_INCLUDES: &'static [Include] = &[
+    Include::Aliases, Include::Annotation, Include::Tags, Include::Genres, Include::UserTags,
+    Include::UserGenres, Include::Ratings, Include::UserRatings
+];
+
+impl Entity for Label {
+    fn search(&self, client: &super::MusicBrainz, params: &mut HashMap<&str, &str>) -> Result<Page<SearchResult<Self>>, Error> {
+        client.search("label", "labels", params, Label::from_json)
+    }
+
+    fn lookup(&self, client: &super::MusicBrainz, entity_id: &Uuid, params: &mut HashMap<&str, &str>) -> Result<Self, Error> {
+        let label_data = match client.get(&format!("label/{id}", id=entity_id), params) {
+            Ok(x) => x,
+            Err(e) => return Err(e)
+        };
+
+        Label::from_json(&label_data)
+    }
+
+    fn search_all<'a>(&self, client: &'a super::MusicBrainz, params: &HashMap<&str, &str>) -> Paginated<'a, SearchResult<Self>> {
+        client.search_all("label", "labels", params, Label::from_json)
+    }
+
+    fn includes(&self, request: Request) -> &'static [Include] {
+        match request {
+            Request::Lookup => LOOKUP_INCLUDES,
+            Request::Browse => BROWSE_INCLUDES,
+            _ => &[]
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncEntity for Label {
+    fn search_async(&self, client: &AsyncMusicBrainz, params: &HashMap<&str, &str>) -> ApiFuture<Page<SearchResult<Self>>> {
+        client.search("label", "labels", params, Label::from_json)
+    }
+
+    fn lookup_async(&self, client: &AsyncMusicBrainz, entity_id: &Uuid, params: &HashMap<&str, &str>) -> ApiFuture<Self> {
+        client.lookup(&format!("label/{id}", id=entity_id), params, Label::from_json)
+    }
+}
+
+/// Entities that can be linked to in a browse request.
+const BROWSE_BY: &'static [&'static str] = &["area", "collection", "release"];
+
+impl Browse for Label {
+    fn browse(&self, client: &super::MusicBrainz, linked: &LinkedEntity, params: &mut HashMap<&str, &str>) -> Result<Page<Self>, Error> {
+        client.browse("label", "labels", BROWSE_BY, linked, params, Label::from_json)
+    }
+
+    fn browse_all<'a>(&self, client: &'a super::MusicBrainz, linked: &LinkedEntity, params: &HashMap<&str, &str>) -> Paginated<'a, Self> {
+        client.browse_all("label", "labels", BROWSE_BY, linked, params, Label::from_json)
+    }
+}
@@ -1,16 +1,41 @@
 extern crate hyper;
 extern crate json;
 extern crate uuid;
-extern crate url;
+extern crate url as liburl;
+#[cfg(feature = "serde")]
+#[macro_use]
+extern crate serde;
+#[cfg(feature = "xml")]
+extern crate roxmltree;
+#[cfg(feature = "chrono")]
+extern crate chrono;
 
 use std::collections::HashMap;
-use std::io::Read;
-use url::{Url};
+use std::fmt;
+use std::io;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+use liburl::Url;
+use transport::{Transport, HyperTransport};
+use rate_limit::RateLimiter;
+use retry::RetryPolicy;
+use format::Format;
 
-#[derive(Debug)]
+const BASE_URI: &'static str = "https://musicbrainz.org/ws/2";
+
+/// The client used to send requests to MusicBrainz.
+///
+/// Cloning a client is cheap; clones share the same transport and rate limiter.
+#[derive(Clone)]
 pub struct MusicBrainz {
-    client: hyper::Client,
-    user_agent: String
+    transport: Arc<dyn Transport>,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    retry_policy: RetryPolicy,
+    base_url: String,
+    user_agent: String,
+    format: Format,
+    min_score: u8
 }
 
 impl MusicBrainz {
@@ -26,50 +51,365 @@ impl MusicBrainz {
     /// let musicbrainz = MusicBrainz::new();
     /// ```
     pub fn new() -> MusicBrainz {
-        let user_agent = format!("{name}/{version} ( {homepage} )",
-            name=env!("CARGO_PKG_NAME"), version=env!("CARGO_PKG_VERSION"),
-            homepage=env!("CARGO_PKG_HOMEPAGE")
-        );
+        MusicBrainz::with_transport(Box::new(HyperTransport::new()))
+    }
 
+    /// Instantiates a new `MusicBrainz` struct sending its requests through `transport`.
+    ///
+    /// See the `transport` module for an example.
+    pub fn with_transport(transport: Box<dyn Transport>) -> MusicBrainz {
         MusicBrainz {
-            client: hyper::Client::new(),
-            user_agent: user_agent
+            transport: Arc::from(transport),
+            rate_limiter: RateLimiter::default_for(BASE_URI).map(Arc::new),
+            retry_policy: RetryPolicy::default(),
+            base_url: BASE_URI.to_string(),
+            user_agent: builder::library_user_agent(),
+            format: Format::Json,
+            min_score: 0
         }
     }
 
-    fn get(&self, url: &str, params: &HashMap<&str, &str>) -> json::Result<json::JsonValue> {
-        let base_uri = "https://musicbrainz.org/ws/2";
-        let mut endpoint = Url::parse(&format!("{}/{}", base_uri, url))
-            .expect("error parsing URL");
+    /// Returns a builder to configure the base URL, User-Agent, timeouts, proxy,
+    /// rate limit and retry policy of a new client.
+    ///
+    /// See `MusicBrainzBuilder` for an example.
+    pub fn builder() -> MusicBrainzBuilder {
+        MusicBrainzBuilder::new()
+    }
+
+    /// Sets the minimum score search hits must have to be returned.
+    ///
+    /// Defaults to `0`, which returns every hit.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use musicbrainz::*;
+    /// let mut musicbrainz = MusicBrainz::new();
+    /// musicbrainz.set_min_score(90);
+    /// ```
+    pub fn set_min_score(&mut self, min_score: u8) {
+        self.min_score = min_score;
+    }
 
-        endpoint.query_pairs_mut().append_pair("fmt", "json");
-        for (param, val) in params {
-            endpoint.query_pairs_mut().append_pair(param, val);
+    pub fn min_score(&self) -> u8 {
+        self.min_score
+    }
+
+    /// Sets the rate limiter requests go through, or disables rate limiting with `None`.
+    ///
+    /// Defaults to one request per second, as required by musicbrainz.org. The limiter
+    /// is shared with the clones of this client made afterwards.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use musicbrainz::*;
+    /// # use musicbrainz::rate_limit::RateLimiter;
+    /// # use std::time::Duration;
+    /// let mut musicbrainz = MusicBrainz::new();
+    /// musicbrainz.set_rate_limit(Some(RateLimiter::new(5, Duration::from_secs(1))));
+    /// ```
+    pub fn set_rate_limit(&mut self, rate_limiter: Option<RateLimiter>) {
+        self.rate_limiter = rate_limiter.map(Arc::new);
+    }
+
+    /// Sets how requests are retried when MusicBrainz is unavailable or throttling the client.
+    ///
+    /// Defaults to `RetryPolicy::default()`. Use `RetryPolicy::none()` to fail on the first error.
+    pub fn set_retry_policy(&mut self, retry_policy: RetryPolicy) {
+        self.retry_policy = retry_policy;
+    }
+
+    /// Sets the format responses are requested in. Entities are the same in every format.
+    ///
+    /// Defaults to `Format::Json`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use musicbrainz::*;
+    /// # use musicbrainz::format::Format;
+    /// let mut musicbrainz = MusicBrainz::new();
+    /// # #[cfg(feature = "xml")]
+    /// musicbrainz.set_format(Format::Xml);
+    /// ```
+    pub fn set_format(&mut self, format: Format) {
+        self.format = format;
+    }
+
+    pub fn format(&self) -> Format {
+        self.format
+    }
+
+    fn get(&self, url: &str, params: &HashMap<&str, &str>) -> Result<json::JsonValue, error::Error> {
+        let request = build_request(&self.base_url, &self.user_agent, self.format, url, params)?;
+
+        let mut attempt = 1;
+        loop {
+            if let Some(ref rate_limiter) = self.rate_limiter {
+                rate_limiter.acquire();
+            }
+
+            let res = match self.transport.send(&request) {
+                Ok(x) => x,
+                Err(e) => return Err(transport_error(e))
+            };
+
+            match check_response(&self.retry_policy, self.format, attempt, &res) {
+                Attempt::Done(result) => return result,
+                Attempt::Retry(delay) => thread::sleep(delay)
+            }
+            attempt += 1;
         }
+    }
+
+    /// Issues a search request against `endpoint`, shared by every `Entity` implementation.
+    ///
+    /// `key` is the plural name under which the hits are returned (e.g. `artists`).
+    /// Hits scoring below `min_score` are left out of the page.
+    fn search<T>(&self, endpoint: &str, key: &str, params: &HashMap<&str, &str>,
+                 parse: fn(&json::JsonValue) -> Result<T, error::Error>) -> Result<browse::Page<search::SearchResult<T>>, error::Error> {
+        let data = match self.get(endpoint, params) {
+            Ok(x) => x,
+            Err(e) => return Err(e)
+        };
+
+        search_page(&data, key, self.min_score, parse)
+    }
+
+    /// Same as `search`, but returns an iterator walking every page of hits.
+    fn search_all<'a, T: 'a>(&'a self, endpoint: &str, key: &str, params: &HashMap<&str, &str>,
+                             parse: fn(&json::JsonValue) -> Result<T, error::Error>) -> paginated::Paginated<'a, search::SearchResult<T>> {
+        paginated::Paginated::new(self, endpoint, key, "count", params, move |hit| search::SearchResult::from_json(hit, parse))
+            .with_min_score(self.min_score)
+    }
+
+    /// Issues a browse request against `endpoint`, shared by every `Browse` implementation.
+    ///
+    /// `key` is the plural name under which the results are returned (e.g. `release-groups`),
+    /// and `allowed` lists the linked entities the endpoint can be browsed by.
+    fn browse<T, F>(&self, endpoint: &str, key: &str, allowed: &[&str], linked: &browse::LinkedEntity,
+                    params: &HashMap<&str, &str>, parse: F) -> Result<browse::Page<T>, error::Error>
+        where F: Fn(&json::JsonValue) -> Result<T, error::Error>
+    {
+        let linked_id = linked.id().hyphenated().to_string();
+        let query = self.browse_query(endpoint, allowed, linked, &linked_id, params)?;
+
+        let data = match self.get(endpoint, &query) {
+            Ok(x) => x,
+            Err(e) => return Err(e)
+        };
+
+        parse::results(&data, key)?;
+        let items = parse::list(&data, key, &parse)?;
 
-        let user_agent = self.user_agent.clone();
-        let mut res = self.client.get(endpoint)
-            .header(hyper::header::UserAgent(user_agent))
-            .send()
-            .expect(&format!("failed to get url '{}'", url));
+        Ok(browse::Page {
+            offset: data[&format!("{}-offset", endpoint) as &str].as_usize().unwrap_or(0),
+            count: data[&format!("{}-count", endpoint) as &str].as_usize().unwrap_or(0),
+            items: items
+        })
+    }
 
-        let mut buf = String::new();
-        res.read_to_string(&mut buf).expect("failed to read response body to string");
+    /// Same as `browse`, but returns an iterator walking every page of results.
+    fn browse_all<'a, T>(&'a self, endpoint: &str, key: &str, allowed: &[&str], linked: &browse::LinkedEntity,
+                         params: &HashMap<&str, &str>, parse: fn(&json::JsonValue) -> Result<T, error::Error>) -> paginated::Paginated<'a, T>
+        where T: 'a
+    {
+        let linked_id = linked.id().hyphenated().to_string();
+        match self.browse_query(endpoint, allowed, linked, &linked_id, params) {
+            Ok(query) => paginated::Paginated::new(self, endpoint, key, &format!("{}-count", endpoint), &query, parse),
+            Err(e) => paginated::Paginated::failed(self, e)
+        }
+    }
 
-        json::parse(&buf)
+    fn browse_query<'a>(&self, endpoint: &str, allowed: &[&str], linked: &browse::LinkedEntity, linked_id: &'a str,
+                        params: &HashMap<&'a str, &'a str>) -> Result<HashMap<&'a str, &'a str>, error::Error> {
+        if !allowed.contains(&linked.name()) {
+            return Err(error::Error::Unsupported(
+                format!("{} cannot be browsed by {}", endpoint, linked.name())
+            ));
+        }
+
+        let mut query = params.clone();
+        query.insert(linked.name(), linked_id);
+        Ok(query)
     }
 
     pub fn artist(&self) -> artist::Artist {
         artist::Artist::empty()
     }
 
+    pub fn release_group(&self) -> release_group::ReleaseGroup {
+        release_group::ReleaseGroup::empty()
+    }
+
+    pub fn release(&self) -> release::Release {
+        release::Release::empty()
+    }
+
+    pub fn recording(&self) -> recording::Recording {
+        recording::Recording::empty()
+    }
+
+    pub fn label(&self) -> label::Label {
+        label::Label::empty()
+    }
+
+    pub fn work(&self) -> work::Work {
+        work::Work::empty()
+    }
+
+    pub fn area(&self) -> area::Area {
+        area::Area::empty()
+    }
+
+    pub fn place(&self) -> place::Place {
+        place::Place::empty()
+    }
+
+    pub fn event(&self) -> event::Event {
+        event::Event::empty()
+    }
+
+    pub fn instrument(&self) -> instrument::Instrument {
+        instrument::Instrument::empty()
+    }
+
+    pub fn series(&self) -> series::Series {
+        series::Series::empty()
+    }
+
+    pub fn genre(&self) -> genre::Genre {
+        genre::Genre::empty()
+    }
+
+    pub fn url(&self) -> url::Url {
+        url::Url::empty()
+    }
+
+}
+
+impl fmt::Debug for MusicBrainz {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MusicBrainz")
+            .field("base_url", &self.base_url)
+            .field("user_agent", &self.user_agent)
+            .field("rate_limiter", &self.rate_limiter)
+            .field("retry_policy", &self.retry_policy)
+            .field("format", &self.format)
+            .field("min_score", &self.min_score)
+            .finish()
+    }
+}
+
+/// Builds the request for `url` relative to `base_url`, shared by the blocking and async clients.
+fn build_request(base_url: &str, user_agent: &str, format: Format, url: &str, params: &HashMap<&str, &str>) -> Result<transport::Request, error::Error> {
+    let mut endpoint = match Url::parse(&format!("{}/{}", base_url, url)) {
+        Ok(x) => x,
+        Err(e) => return Err(error::Error::InvalidUrl(e))
+    };
+
+    endpoint.query_pairs_mut().append_pair("fmt", format.param());
+    for (param, val) in params {
+        endpoint.query_pairs_mut().append_pair(param, val);
+    }
+
+    Ok(transport::Request {
+        url: endpoint.to_string(),
+        headers: vec![(String::from("User-Agent"), user_agent.to_string())]
+    })
+}
+
+/// What to do once a response has been received.
+enum Attempt {
+    Done(Result<json::JsonValue, error::Error>),
+    /// Send the request again after waiting for the given delay.
+    Retry(Duration)
+}
+
+/// Parses the response to the given attempt, or decides to retry it according to `retry_policy`.
+fn check_response(retry_policy: &RetryPolicy, format: Format, attempt: u32, res: &transport::Response) -> Attempt {
+    if res.status >= 200 && res.status < 300 {
+        return Attempt::Done(format.parse(&res.body));
+    }
+
+    if !retry_policy.should_retry(res.status) || attempt >= retry_policy.max_attempts {
+        return Attempt::Done(Err(error::Error::from_status(res.status, &res.body, attempt)));
+    }
+
+    Attempt::Retry(retry_policy.backoff(attempt, res))
+}
+
+fn transport_error(e: Box<dyn std::error::Error + Send + Sync>) -> error::Error {
+    match e.downcast::<io::Error>() {
+        Ok(io_error) => error::Error::Io(*io_error),
+        Err(e) => error::Error::Transport(e)
+    }
+}
+
+/// Reads a page of search hits, leaving out those scoring below `min_score`.
+fn search_page<T>(data: &json::JsonValue, key: &str, min_score: u8,
+                  parse: fn(&json::JsonValue) -> Result<T, error::Error>) -> Result<browse::Page<search::SearchResult<T>>, error::Error> {
+    let mut items: Vec<search::SearchResult<T>> = Vec::new();
+    for (i, hit) in parse::results(data, key)?.members().enumerate() {
+        let result = match search::SearchResult::from_json(hit, parse) {
+            Ok(x) => x,
+            Err(e) => return Err(e.within(&format!("{}[{}]", key, i)))
+        };
+        if result.score >= min_score {
+            items.push(result);
+        }
+    }
+
+    Ok(browse::Page {
+        offset: data["offset"].as_usize().unwrap_or(0),
+        count: data["count"].as_usize().unwrap_or(0),
+        items: items
+    })
 }
 
 pub mod artist;
 pub mod release_group;
+pub mod release;
+pub mod recording;
+pub mod medium;
+pub mod artist_credit;
+pub mod label;
+pub mod life_span;
+pub mod partial_date;
+pub mod relationship;
+pub mod work;
+pub mod area;
+pub mod place;
+pub mod event;
+pub mod instrument;
+pub mod series;
+pub mod genre;
+pub mod url;
+pub mod browse;
+pub mod paginated;
+pub mod search;
+pub mod query;
+pub mod include;
+pub mod transport;
+pub mod rate_limit;
+pub mod retry;
+pub mod builder;
+pub mod format;
+#[cfg(feature = "async")]
+pub mod async_client;
+#[cfg(feature = "testing")]
+pub mod fixture;
 pub mod enums;
 pub mod traits;
 pub mod error;
+mod parse;
+#[cfg(feature = "serde")]
+mod serde_impls;
+#[cfg(feature = "xml")]
+mod xml;
 
 pub use traits::*;
-pub use uuid::Uuid;
\ No newline at end of file
+pub use uuid::Uuid;
+pub use builder::MusicBrainzBuilder;
\ No newline at end of file
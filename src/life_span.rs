@@ -0,0 +1,36 @@
+use json::JsonValue;
+use partial_date::PartialDate;
+use error::Error;
+use parse;
+
+/// The period during which an entity (a label, an area, a place...) existed or was active.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub struct LifeSpan {
+    #[cfg_attr(feature = "serde", serde(default, with = "::serde_impls::nullable"))]
+    pub begin: PartialDate,
+    #[cfg_attr(feature = "serde", serde(default, with = "::serde_impls::nullable"))]
+    pub end: PartialDate,
+    #[cfg_attr(feature = "serde", serde(default, with = "::serde_impls::nullable"))]
+    pub ended: bool
+}
+
+impl LifeSpan {
+    pub fn empty() -> LifeSpan {
+        LifeSpan {
+            begin: PartialDate::empty(),
+            end: PartialDate::empty(),
+            ended: false
+        }
+    }
+
+    /// Parses the `life-span` object of a web service response.
+    pub fn from_json(life_span: &JsonValue) -> Result<LifeSpan, Error> {
+        Ok(LifeSpan {
+            begin: parse::value(life_span, "begin", PartialDate::empty())?,
+            end: parse::value(life_span, "end", PartialDate::empty())?,
+            ended: life_span["ended"].as_bool().unwrap_or(false)
+        })
+    }
+}
@@ -0,0 +1,83 @@
+use uuid::Uuid;
+use artist_credit::ArtistCredit;
+use error::Error;
+use parse;
+use json::JsonValue;
+
+/// A physical or digital medium of a release, e.g. a single CD of a box set.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub struct Medium {
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub position: u32,
+    #[cfg_attr(feature = "serde", serde(default, with = "::serde_impls::nullable"))]
+    pub title: String,
+    #[cfg_attr(feature = "serde", serde(default, with = "::serde_impls::nullable"))]
+    pub format: String,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub track_count: u32,
+    /// Disc IDs of the medium, only present for CDs.
+    #[cfg_attr(feature = "serde", serde(rename = "discs", default, with = "::serde_impls::ids"))]
+    pub disc_ids: Vec<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub tracks: Vec<Track>
+}
+
+/// A track on a medium, linking a position in the tracklist to a recording.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub struct Track {
+    #[cfg_attr(feature = "serde", serde(with = "::serde_impls::uuid"))]
+    pub id: Uuid,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub position: u32,
+    /// The number printed on the medium, which is not always numeric (e.g. `A1`).
+    #[cfg_attr(feature = "serde", serde(default, with = "::serde_impls::nullable"))]
+    pub number: String,
+    #[cfg_attr(feature = "serde", serde(default, with = "::serde_impls::nullable"))]
+    pub title: String,
+    /// Length of the track in milliseconds, if known.
+    pub length: Option<u32>,
+    #[cfg_attr(feature = "serde", serde(default = "Uuid::nil", with = "::serde_impls::linked_id"))]
+    pub recording: Uuid,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub artist_credit: ArtistCredit
+}
+
+impl Medium {
+    /// Parses a single entry of the `media` array of a release lookup.
+    pub(crate) fn from_json(medium: &JsonValue) -> Result<Medium, Error> {
+        Ok(Medium {
+            position: medium["position"].as_u32().unwrap_or(0),
+            title: medium["title"].as_str().unwrap_or("").to_string(),
+            format: medium["format"].as_str().unwrap_or("").to_string(),
+            track_count: medium["track-count"].as_u32().unwrap_or(0),
+            disc_ids: parse::list(medium, "discs", |disc| parse::string(disc, "id"))?,
+            tracks: parse::list(medium, "tracks", Track::from_json)?
+        })
+    }
+}
+
+impl Track {
+    fn from_json(track: &JsonValue) -> Result<Track, Error> {
+        let id = parse::mbid(track, "id")?;
+
+        let recording = if track["recording"].is_null() {
+            Uuid::nil()
+        } else {
+            parse::mbid(&track["recording"], "id").map_err(|e| e.within("recording"))?
+        };
+
+        Ok(Track {
+            id: id,
+            position: track["position"].as_u32().unwrap_or(0),
+            number: track["number"].as_str().unwrap_or("").to_string(),
+            title: parse::string(track, "title")?,
+            length: track["length"].as_u32(),
+            recording: recording,
+            artist_credit: ArtistCredit::from_json(track)?
+        })
+    }
+}
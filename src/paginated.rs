@@ -0,0 +1,187 @@
+use std::collections::{HashMap, VecDeque};
+use error::Error;
+use json::JsonValue;
+use search;
+use parse;
+
+/// Maximum number of results the web service returns in a single page.
+const MAX_LIMIT: usize = 100;
+
+/// A lazy iterator over every result of a search or browse request.
+///
+/// Pages are requested one at a time, using the `limit` and `offset` parameters,
+/// whenever the results of the previous page have been consumed. Iteration stops
+/// once the total count reported by the web service is reached, or after the
+/// first error, which is yielded as the last item.
+///
+/// # Example
+///
+/// ```no_run
+/// # use musicbrainz::*;
+/// # use musicbrainz::browse::LinkedEntity;
+/// # use std::collections::HashMap;
+/// let musicbrainz = MusicBrainz::new();
+/// let query = HashMap::new();
+///
+/// let artist_id = Uuid::parse_str("4a00ec9d-c635-463a-8cd4-eb61725f0c60").expect("failed to parse artist ID as Uuid");
+/// for release_group in musicbrainz.release_group().browse_all(&musicbrainz, &LinkedEntity::Artist(artist_id), &query) {
+///     println!("{}", release_group.unwrap());
+/// }
+/// ```
+pub struct Paginated<'a, T> {
+    client: &'a super::MusicBrainz,
+    endpoint: String,
+    key: String,
+    count_key: String,
+    params: Vec<(String, String)>,
+    parse: Box<dyn Fn(&JsonValue) -> Result<T, Error> + 'a>,
+    min_score: u8,
+    limit: usize,
+    offset: usize,
+    count: Option<usize>,
+    buffer: VecDeque<T>,
+    error: Option<Error>,
+    done: bool
+}
+
+impl<'a, T: 'a> Paginated<'a, T> {
+    /// Creates an iterator over the results of `endpoint`.
+    ///
+    /// `key` is the name of the array holding the results and `count_key` the name
+    /// of the field holding the total number of results (`count` for searches,
+    /// `<entity>-count` for browse requests).
+    pub fn new<F>(client: &'a super::MusicBrainz, endpoint: &str, key: &str, count_key: &str,
+                  params: &HashMap<&str, &str>, parse: F) -> Paginated<'a, T>
+        where F: Fn(&JsonValue) -> Result<T, Error> + 'a
+    {
+        let limit = match params.get("limit") {
+            Some(x) => x.parse::<usize>().unwrap_or(MAX_LIMIT),
+            None => MAX_LIMIT
+        };
+
+        let offset = match params.get("offset") {
+            Some(x) => x.parse::<usize>().unwrap_or(0),
+            None => 0
+        };
+
+        let params = params.iter()
+            .filter(|&(param, _)| *param != "limit" && *param != "offset")
+            .map(|(param, val)| (param.to_string(), val.to_string()))
+            .collect();
+
+        Paginated {
+            client: client,
+            endpoint: endpoint.to_string(),
+            key: key.to_string(),
+            count_key: count_key.to_string(),
+            params: params,
+            parse: Box::new(parse),
+            min_score: 0,
+            limit: limit,
+            offset: offset,
+            count: None,
+            buffer: VecDeque::new(),
+            error: None,
+            done: false
+        }
+    }
+
+    /// Creates an iterator which only yields `error`, for requests that are known to fail.
+    pub fn failed(client: &'a super::MusicBrainz, error: Error) -> Paginated<'a, T> {
+        let mut paginated = Paginated::new(client, "", "", "", &HashMap::new(), |_| Err(Error::Unsupported(String::from("failed request"))));
+        paginated.error = Some(error);
+        paginated
+    }
+
+    /// Stops the iteration at the first search hit scoring below `min_score`.
+    ///
+    /// Search hits are sorted by descending score, so none of the following
+    /// pages need to be requested once a hit falls below the threshold.
+    pub fn with_min_score(mut self, min_score: u8) -> Paginated<'a, T> {
+        self.min_score = min_score;
+        self
+    }
+
+    /// Total number of results, known once the first page has been requested.
+    pub fn count(&self) -> Option<usize> {
+        self.count
+    }
+
+    fn fetch(&mut self) -> Result<(), Error> {
+        let limit = self.limit.to_string();
+        let offset = self.offset.to_string();
+
+        let mut query: HashMap<&str, &str> = HashMap::new();
+        for &(ref param, ref val) in &self.params {
+            query.insert(param, val);
+        }
+        query.insert("limit", &limit);
+        query.insert("offset", &offset);
+
+        let data = match self.client.get(&self.endpoint, &query) {
+            Ok(x) => x,
+            Err(e) => return Err(e)
+        };
+
+        let results = parse::results(&data, &self.key)?;
+        self.count = data[self.count_key.as_str()].as_usize();
+
+        let mut fetched = 0;
+        for item in results.members() {
+            if let Some(score) = search::score(item) {
+                if score < self.min_score {
+                    self.done = true;
+                    break;
+                }
+            }
+
+            match (self.parse)(item) {
+                Ok(x) => self.buffer.push_back(x),
+                Err(e) => return Err(e.within(&format!("{}[{}]", self.key, fetched)))
+            }
+            fetched += 1;
+        }
+
+        self.offset += fetched;
+        if fetched == 0 {
+            self.done = true;
+        }
+        Ok(())
+    }
+}
+
+impl<'a, T: 'a> Iterator for Paginated<'a, T> {
+    type Item = Result<T, Error>;
+
+    fn next(&mut self) -> Option<Result<T, Error>> {
+        if let Some(item) = self.buffer.pop_front() {
+            return Some(Ok(item));
+        }
+
+        if let Some(error) = self.error.take() {
+            self.done = true;
+            return Some(Err(error));
+        }
+
+        if self.done {
+            return None;
+        }
+
+        if let Some(count) = self.count {
+            if self.offset >= count {
+                self.done = true;
+                return None;
+            }
+        }
+
+        if let Err(error) = self.fetch() {
+            self.done = true;
+            return Some(Err(error));
+        }
+
+        match self.buffer.pop_front() {
+            Some(item) => Some(Ok(item)),
+            None => None
+        }
+    }
+}
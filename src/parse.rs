@@ -0,0 +1,97 @@
+//! Helpers reading the fields of a JSON response, reporting the path of the
+//! field when it is missing or malformed.
+
+use std::str::FromStr;
+use error::Error;
+use json::JsonValue;
+use uuid::Uuid;
+
+/// Reads the required MBID held by `field`.
+pub fn mbid(data: &JsonValue, field: &str) -> Result<Uuid, Error> {
+    match data[field].as_str() {
+        Some(x) => match Uuid::parse_str(x) {
+            Ok(y) => Ok(y),
+            Err(e) => Err(Error::InvalidMbid { path: field.to_string(), source: e })
+        },
+        None if data[field].is_null() => Err(Error::MissingField(field.to_string())),
+        None => Err(Error::InvalidType { path: field.to_string(), expected: "string" })
+    }
+}
+
+/// Reads the required string held by `field`.
+pub fn string(data: &JsonValue, field: &str) -> Result<String, Error> {
+    match data[field].as_str() {
+        Some(x) => Ok(x.to_string()),
+        None if data[field].is_null() => Err(Error::MissingField(field.to_string())),
+        None => Err(Error::InvalidType { path: field.to_string(), expected: "string" })
+    }
+}
+
+/// Reads the array of strings held by `field`, which may be absent.
+pub fn strings(data: &JsonValue, field: &str) -> Result<Vec<String>, Error> {
+    let mut results: Vec<String> = Vec::new();
+    for (i, value) in data[field].members().enumerate() {
+        match value.as_str() {
+            Some(x) => results.push(x.to_string()),
+            None => return Err(Error::InvalidType { path: format!("{}[{}]", field, i), expected: "string" })
+        }
+    }
+    Ok(results)
+}
+
+/// Reads the value of `field` as one of the known values of `T`, or `default` if it is absent.
+pub fn value<T: FromStr>(data: &JsonValue, field: &str, default: T) -> Result<T, Error> {
+    Ok(optional(data, field)?.unwrap_or(default))
+}
+
+/// Reads the value of `field` as one of the known values of `T`, or `None` if it is absent.
+pub fn optional<T: FromStr>(data: &JsonValue, field: &str) -> Result<Option<T>, Error> {
+    match data[field].as_str() {
+        Some(x) => match x.parse::<T>() {
+            Ok(y) => Ok(Some(y)),
+            Err(_) => Err(Error::UnknownValue { path: field.to_string(), value: x.to_string() })
+        },
+        None if data[field].is_null() => Ok(None),
+        None => Err(Error::InvalidType { path: field.to_string(), expected: "string" })
+    }
+}
+
+/// Reads the array of known values held by `field`, which may be absent.
+pub fn values<T: FromStr>(data: &JsonValue, field: &str) -> Result<Vec<T>, Error> {
+    let mut results: Vec<T> = Vec::new();
+    for (i, value) in data[field].members().enumerate() {
+        match value.as_str() {
+            Some(x) => match x.parse::<T>() {
+                Ok(y) => results.push(y),
+                Err(_) => return Err(Error::UnknownValue { path: format!("{}[{}]", field, i), value: x.to_string() })
+            },
+            None => return Err(Error::InvalidType { path: format!("{}[{}]", field, i), expected: "string" })
+        }
+    }
+    Ok(results)
+}
+
+/// Parses every element of the array held by `field` with `parse`.
+pub fn list<T, F>(data: &JsonValue, field: &str, parse: F) -> Result<Vec<T>, Error>
+    where F: Fn(&JsonValue) -> Result<T, Error>
+{
+    let mut results: Vec<T> = Vec::new();
+    for (i, value) in data[field].members().enumerate() {
+        match parse(value) {
+            Ok(x) => results.push(x),
+            Err(e) => return Err(e.within(&format!("{}[{}]", field, i)))
+        }
+    }
+    Ok(results)
+}
+
+/// Checks that `field` holds the array of results of a search or browse request.
+pub fn results<'a>(data: &'a JsonValue, field: &str) -> Result<&'a JsonValue, Error> {
+    if data[field].is_array() {
+        Ok(&data[field])
+    } else if data[field].is_null() {
+        Err(Error::MissingField(field.to_string()))
+    } else {
+        Err(Error::InvalidType { path: field.to_string(), expected: "array" })
+    }
+}
@@ -0,0 +1,160 @@
+use std::str::FromStr;
+use std::fmt;
+#[cfg(feature = "chrono")]
+use chrono::{NaiveDate, Datelike};
+#[cfg(feature = "serde")]
+use serde::{Serialize, Serializer, Deserialize, Deserializer};
+#[cfg(feature = "serde")]
+use serde::de::Error;
+
+/// A date as stored by MusicBrainz, which may only be known to the year
+/// (`2009`) or to the month (`2009-09`) rather than to the day (`2009-09-22`).
+///
+/// Dates are ordered by year, then month, then day, a less precise date sorting
+/// before the more precise dates it contains: `2009` < `2009-09` < `2009-09-22` < `2009-10`.
+/// The empty date, used when the date is unknown, sorts before any other.
+///
+/// # Example
+///
+/// ```
+/// # use musicbrainz::partial_date::PartialDate;
+/// let date = "2009-09".parse::<PartialDate>().unwrap();
+///
+/// assert_eq!(date.year, Some(2009));
+/// assert_eq!(date.day, None);
+/// assert_eq!(date.to_string(), "2009-09");
+/// assert!(date < "2009-09-22".parse::<PartialDate>().unwrap());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct PartialDate {
+    pub year: Option<u16>,
+    pub month: Option<u8>,
+    pub day: Option<u8>
+}
+
+impl PartialDate {
+    pub fn new(year: Option<u16>, month: Option<u8>, day: Option<u8>) -> PartialDate {
+        PartialDate {
+            year: year,
+            month: month,
+            day: day
+        }
+    }
+
+    pub fn empty() -> PartialDate {
+        PartialDate::new(None, None, None)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.year.is_none() && self.month.is_none() && self.day.is_none()
+    }
+
+    /// Whether the year, month and day are all known.
+    pub fn is_complete(&self) -> bool {
+        self.year.is_some() && self.month.is_some() && self.day.is_some()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl PartialDate {
+    /// Converts the date to a `chrono::NaiveDate`, if it is complete and exists.
+    ///
+    /// Only available with the `chrono` feature.
+    pub fn to_naive_date(&self) -> Option<NaiveDate> {
+        match (self.year, self.month, self.day) {
+            (Some(year), Some(month), Some(day)) => NaiveDate::from_ymd_opt(year as i32, month as u32, day as u32),
+            _ => None
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<NaiveDate> for PartialDate {
+    /// Only available with the `chrono` feature. Years outside `0..=65535` are left out.
+    fn from(date: NaiveDate) -> PartialDate {
+        let year = if date.year() >= 0 && date.year() <= u16::max_value() as i32 { Some(date.year() as u16) } else { None };
+        PartialDate::new(year, Some(date.month() as u8), Some(date.day() as u8))
+    }
+}
+
+impl FromStr for PartialDate {
+    type Err = ();
+
+    /// Parses `YYYY`, `YYYY-MM` or `YYYY-MM-DD`, or the empty string as the empty date.
+    /// Unknown parts are written with question marks, as in `????-04-12` or `1987-??-03`.
+    fn from_str(s: &str) -> Result<PartialDate, ()> {
+        if s.is_empty() {
+            return Ok(PartialDate::empty());
+        }
+
+        let parts: Vec<&str> = s.split('-').collect();
+        if parts.len() > 3 {
+            return Err(());
+        }
+
+        let widths = [4, 2, 2];
+        let mut values: Vec<Option<u16>> = Vec::new();
+        for (part, width) in parts.iter().zip(widths.iter()) {
+            if part.len() != *width {
+                return Err(());
+            }
+            if part.chars().all(|c| c == '?') {
+                values.push(None);
+            } else if part.chars().all(|c| c.is_ascii_digit()) {
+                values.push(Some(part.parse::<u16>().map_err(|_| ())?));
+            } else {
+                return Err(());
+            }
+        }
+
+        let month = values.get(1).cloned().and_then(|x| x).map(|x| x as u8);
+        let day = values.get(2).cloned().and_then(|x| x).map(|x| x as u8);
+        if month.map_or(false, |x| x < 1 || x > 12) || day.map_or(false, |x| x < 1 || x > 31) {
+            return Err(());
+        }
+
+        Ok(PartialDate::new(values[0], month, day))
+    }
+}
+
+impl fmt::Display for PartialDate {
+    /// Writes the date the way MusicBrainz does, leaving out the trailing unknown
+    /// parts and writing the others as question marks, e.g. `????-04-12`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_empty() {
+            return Ok(());
+        }
+
+        match self.year {
+            Some(year) => write!(f, "{:04}", year)?,
+            None => write!(f, "????")?
+        }
+        if self.month.is_none() && self.day.is_none() {
+            return Ok(());
+        }
+
+        match self.month {
+            Some(month) => write!(f, "-{:02}", month)?,
+            None => write!(f, "-??")?
+        }
+        if let Some(day) = self.day {
+            write!(f, "-{:02}", day)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for PartialDate {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for PartialDate {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<PartialDate, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        value.parse::<PartialDate>().map_err(|_| D::Error::custom(format!("invalid date '{}'", value)))
+    }
+}
@@ -0,0 +1,193 @@
+use uuid::Uuid;
+use life_span::LifeSpan;
+use std::collections::HashMap;
+use std::fmt;
+use traits::{Entity, Browse};
+use browse::{LinkedEntity, Page};
+use error::Error;
+use parse;
+use include::{Include, Request};
+use paginated::Paginated;
+use search::SearchResult;
+use json::JsonValue;
+use relationship::Relationship;
+#[cfg(feature = "async")]
+use traits::AsyncEntity;
+#[cfg(feature = "async")]
+use async_client::{AsyncMusicBrainz, ApiFuture};
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub struct Place {
+    #[cfg_attr(feature = "serde", serde(with = "::serde_impls::uuid"))]
+    pub id: Uuid,
+    pub name: String,
+    #[cfg_attr(feature = "serde", serde(rename = "type", default, with = "::serde_impls::nullable"))]
+    pub place_type: String,
+    #[cfg_attr(feature = "serde", serde(default, with = "::serde_impls::nullable"))]
+    pub disambiguation: String,
+    #[cfg_attr(feature = "serde", serde(default, with = "::serde_impls::nullable"))]
+    pub address: String,
+    #[cfg_attr(feature = "serde", serde(default, with = "::serde_impls::name"))]
+    pub area: String,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub coordinates: Option<Coordinates>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub life_span: LifeSpan,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub relations: Vec<Relationship>
+}
+
+/// Geographic coordinates of a place, in decimal degrees.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Coordinates {
+    #[cfg_attr(feature = "serde", serde(with = "::serde_impls::number"))]
+    pub latitude: f64,
+    #[cfg_attr(feature = "serde", serde(with = "::serde_impls::number"))]
+    pub longitude: f64
+}
+
+impl Coordinates {
+    fn from_json(coordinates: &JsonValue) -> Option<Coordinates> {
+        // Depending on the server version, coordinates are either numbers or strings.
+        let parse = |value: &JsonValue| -> Option<f64> {
+            match value.as_f64() {
+                Some(x) => Some(x),
+                None => value.as_str().and_then(|x| x.parse::<f64>().ok())
+            }
+        };
+
+        match (parse(&coordinates["latitude"]), parse(&coordinates["longitude"])) {
+            (Some(latitude), Some(longitude)) => Some(Coordinates { latitude: latitude, longitude: longitude }),
+            _ => None
+        }
+    }
+}
+
+impl Place {
+    pub fn new(id: Uuid, name: String, place_type: String, disambiguation: String, address: String, area: String,
+               coordinates: Option<Coordinates>, life_span: LifeSpan) -> Place {
+        Place {
+            id: id,
+            name: name,
+            place_type: place_type,
+            disambiguation: disambiguation,
+            address: address,
+            area: area,
+            coordinates: coordinates,
+            life_span: life_span,
+            relations: Vec::new()
+        }
+    }
+
+    pub fn empty() -> Place {
+        Place::new(
+            Uuid::nil(),
+            String::new(),
+            String::new(),
+            String::new(),
+            String::new(),
+            String::new(),
+            None,
+            LifeSpan::empty()
+        )
+    }
+
+    pub(crate) fn from_json(place: &JsonValue) -> Result<Place, Error> {
+        let id = parse::mbid(place, "id")?;
+
+        let mut result = Place::new(
+            id,
+            parse::string(place, "name")?,
+            place["type"].as_str().unwrap_or("").to_string(),
+            place["disambiguation"].as_str().unwrap_or("").to_string(),
+            place["address"].as_str().unwrap_or("").to_string(),
+            place["area"]["name"].as_str().unwrap_or("").to_string(),
+            Coordinates::from_json(&place["coordinates"]),
+            match LifeSpan::from_json(&place["life-span"]) {
+                Ok(x) => x,
+                Err(e) => return Err(e.within("life-span"))
+            }
+        );
+        result.relations = Relationship::from_json(place)?;
+        Ok(result)
+    }
+}
+
+impl PartialEq for Place {
+    fn eq(&self, other: &Place) -> bool {
+        self.id == other.id && self.name == other.name
+    }
+}
+
+impl fmt::Display for Place {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{name} ({type})", name=self.name, type=self.place_type)?;
+        writeln!(f, "Id: {id}", id=self.id)
+    }
+}
+
+/// Subqueries that can be included in a lookup request.
+const LOOKUP_INCLUDES: &'static [Include] = &[
+    Include::Aliases, Include::Annotation, Include::Tags, Include::Genres, Include::UserTags,
+    Include::UserGenres
+];
+
+/// Subqueries that can be included in a browse request.
+const BROWSE_INCLUDES: &'static [Include] = &[
+    Include::Aliases, Include::Annotation, Include::Tags, Include::Genres, Include::UserTags,
+    Include::UserGenres
+];
+
+impl Entity for Place {
+    fn search(&self, client: &super::MusicBrainz, params: &mut HashMap<&str, &str>) -> Result<Page<SearchResult<Self>>, Error> {
+        client.search("place", "places", params, Place::from_json)
+    }
+
+    fn lookup(&self, client: &super::MusicBrainz, entity_id: &Uuid, params: &mut HashMap<&str, &str>) -> Result<Self, Error> {
+        let place_data = match client.get(&format!("place/{id}", id=entity_id), params) {
+            Ok(x) => x,
+            Err(e) => return Err(e)
+        };
+
+        Place::from_json(&place_data)
+    }
+
+    fn search_all<'a>(&self, client: &'a super::MusicBrainz, params: &HashMap<&str, &str>) -> Paginated<'a, SearchResult<Self>> {
+        client.search_all("place", "places", params, Place::from_json)
+    }
+
+    fn includes(&self, request: Request) -> &'static [Include] {
+        match request {
+            Request::Lookup => LOOKUP_INCLUDES,
+            Request::Browse => BROWSE_INCLUDES,
+            _ => &[]
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncEntity for Place {
+    fn search_async(&self, client: &AsyncMusicBrainz, params: &HashMap<&str, &str>) -> ApiFuture<Page<SearchResult<Self>>> {
+        client.search("place", "places", params, Place::from_json)
+    }
+
+    fn lookup_async(&self, client: &AsyncMusicBrainz, entity_id: &Uuid, params: &HashMap<&str, &str>) -> ApiFuture<Self> {
+        client.lookup(&format!("place/{id}", id=entity_id), params, Place::from_json)
+    }
+}
+
+/// Entities that can be linked to in a browse request.
+const BROWSE_BY: &'static [&'static str] = &["area", "collection"];
+
+impl Browse for Place {
+    fn browse(&self, client: &super::MusicBrainz, linked: &LinkedEntity, params: &mut HashMap<&str, &str>) -> Result<Page<Self>, Error> {
+        client.browse("place", "places", BROWSE_BY, linked, params, Place::from_json)
+    }
+
+    fn browse_all<'a>(&self, client: &'a super::MusicBrainz, linked: &LinkedEntity, params: &HashMap<&str, &str>) -> Paginated<'a, Self> {
+        client.browse_all("place", "places", BROWSE_BY, linked, params, Place::from_json)
+    }
+}
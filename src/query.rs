@@ -0,0 +1,537 @@
+//! Typed builders for Lucene search queries.
+//!
+//! Each searchable entity has its own builder exposing the fields its search
+//! endpoint supports, so that queries can be written without hand-escaping
+//! Lucene syntax:
+//!
+//! ```no_run
+//! # use musicbrainz::*;
+//! # use musicbrainz::query::ArtistQuery;
+//! # use musicbrainz::enums::PersonType;
+//! let musicbrainz = MusicBrainz::new();
+//! let query = ArtistQuery::new()
+//!     .name("deadmau5")
+//!     .country("CA")
+//!     .type_(PersonType::Person);
+//!
+//! let results = musicbrainz.artist().search_by(&musicbrainz, &query).unwrap();
+//! ```
+
+use std::fmt;
+use error::Error;
+use enums::{PersonType, AlbumType, ReleaseStatus, LabelType};
+use uuid::Uuid;
+
+/// Characters with a special meaning in the Lucene query syntax.
+const SPECIAL_CHARS: &'static str = "+-&|!(){}[]^\"~*?:\\/";
+
+/// Escapes every Lucene special character and whitespace in `s` with a backslash.
+pub fn escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        if SPECIAL_CHARS.contains(c) || c.is_whitespace() {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// The value a field is matched against.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Term {
+    /// A single word, matched as-is after escaping.
+    Word(String),
+    /// An exact sequence of words, e.g. `"daft punk"`.
+    Phrase(String),
+    /// A word matched with the given maximum edit distance (0 to 2), e.g. `deadmouse~2`.
+    /// Larger distances are written as 2.
+    Fuzzy(String, u8),
+    /// A range of values, bounds included, e.g. `[1990 TO 1999]`. `None` leaves a bound open.
+    Range(Option<String>, Option<String>)
+}
+
+impl Term {
+    pub fn word(s: &str) -> Term {
+        Term::Word(s.to_string())
+    }
+
+    pub fn phrase(s: &str) -> Term {
+        Term::Phrase(s.to_string())
+    }
+
+    /// A word matched with a maximum edit distance, returning `Error::Unsupported`
+    /// if `distance` is greater than 2, the largest distance Lucene supports.
+    pub fn fuzzy(s: &str, distance: u8) -> Result<Term, Error> {
+        if distance > 2 {
+            return Err(Error::Unsupported(format!("fuzzy distance {} is greater than 2", distance)));
+        }
+        Ok(Term::Fuzzy(s.to_string(), distance))
+    }
+
+    /// A range between two values, typically dates such as `1990` or `1990-05-01`.
+    pub fn range(from: &str, to: &str) -> Term {
+        Term::Range(Some(from.to_string()), Some(to.to_string()))
+    }
+}
+
+impl fmt::Display for Term {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Term::Word(ref s) => write!(f, "{}", escape(s)),
+            Term::Phrase(ref s) => write!(f, "\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\"")),
+            Term::Fuzzy(ref s, distance) => write!(f, "{}~{}", escape(s), distance.min(2)),
+            Term::Range(ref from, ref to) => {
+                let bound = |b: &Option<String>| match *b {
+                    Some(ref x) => escape(x),
+                    None => String::from("*")
+                };
+                write!(f, "[{} TO {}]", bound(from), bound(to))
+            }
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Term {
+    fn from(s: &'a str) -> Term {
+        Term::Phrase(s.to_string())
+    }
+}
+
+impl From<String> for Term {
+    fn from(s: String) -> Term {
+        Term::Phrase(s)
+    }
+}
+
+impl From<Uuid> for Term {
+    fn from(id: Uuid) -> Term {
+        Term::Word(id.hyphenated().to_string())
+    }
+}
+
+impl From<bool> for Term {
+    fn from(b: bool) -> Term {
+        Term::Word(b.to_string())
+    }
+}
+
+impl From<u32> for Term {
+    fn from(n: u32) -> Term {
+        Term::Word(n.to_string())
+    }
+}
+
+impl From<PersonType> for Term {
+    fn from(t: PersonType) -> Term {
+        Term::Phrase(t.to_string())
+    }
+}
+
+impl From<AlbumType> for Term {
+    fn from(t: AlbumType) -> Term {
+        Term::Phrase(t.to_string())
+    }
+}
+
+impl From<ReleaseStatus> for Term {
+    fn from(t: ReleaseStatus) -> Term {
+        Term::Phrase(t.to_string())
+    }
+}
+
+impl From<LabelType> for Term {
+    fn from(t: LabelType) -> Term {
+        Term::Phrase(t.to_string())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Operator {
+    And,
+    Or,
+    Not
+}
+
+/// The untyped query shared by every entity-specific builder.
+#[derive(Debug, Clone)]
+struct Query {
+    clauses: Vec<(Operator, String)>,
+    next: Operator
+}
+
+impl Query {
+    fn new() -> Query {
+        Query {
+            clauses: Vec::new(),
+            next: Operator::And
+        }
+    }
+
+    fn push(mut self, clause: String) -> Query {
+        self.clauses.push((self.next, clause));
+        self.next = Operator::And;
+        self
+    }
+
+    fn operator(mut self, operator: Operator) -> Query {
+        self.next = operator;
+        self
+    }
+}
+
+impl fmt::Display for Query {
+    /// Clauses are combined from left to right: whenever the operator switches
+    /// between `OR` and `AND`/`AND NOT`, the clauses written so far are wrapped in
+    /// parentheses, as Lucene would otherwise give `AND` precedence over `OR`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut result = String::new();
+        let mut previous: Option<bool> = None;
+        for (i, &(operator, ref clause)) in self.clauses.iter().enumerate() {
+            let disjunction = operator == Operator::Or;
+            if i == 0 {
+                if operator == Operator::Not {
+                    result.push_str("NOT ");
+                    previous = Some(false);
+                }
+                result.push_str(clause);
+                continue;
+            }
+
+            if previous.map_or(false, |x| x != disjunction) {
+                result = format!("({})", result);
+            }
+            previous = Some(disjunction);
+
+            match operator {
+                Operator::And => result.push_str(" AND "),
+                Operator::Or => result.push_str(" OR "),
+                Operator::Not => result.push_str(" AND NOT ")
+            }
+            result.push_str(clause);
+        }
+        write!(f, "{}", result)
+    }
+}
+
+/// A search query targeting a specific entity, accepted by `Entity::search_by`.
+pub trait SearchQuery: fmt::Display {
+    type Entity;
+}
+
+macro_rules! search_query {
+    ($(#[$meta:meta])* $name:ident, $entity:ty, { $($method:ident => $field:expr),* }) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone)]
+        pub struct $name {
+            query: Query
+        }
+
+        impl $name {
+            /// Names of the fields this query can be built from.
+            pub const FIELDS: &'static [&'static str] = &[$($field),*];
+
+            pub fn new() -> $name {
+                $name { query: Query::new() }
+            }
+
+            $(
+                #[doc = concat!("Matches the `", $field, "` field.")]
+                pub fn $method<T: Into<Term>>(self, term: T) -> $name {
+                    $name { query: self.query.push(format!("{}:{}", $field, term.into())) }
+                }
+            )*
+
+            /// Matches a field by name, returning `Error::Unsupported` if this entity
+            /// cannot be searched by it.
+            pub fn field<T: Into<Term>>(self, name: &str, term: T) -> Result<$name, Error> {
+                if !$name::FIELDS.contains(&name) {
+                    return Err(Error::Unsupported(format!("unknown search field '{}'", name)));
+                }
+                Ok($name { query: self.query.push(format!("{}:{}", name, term.into())) })
+            }
+
+            /// Matches a term against the default fields of the entity.
+            pub fn any<T: Into<Term>>(self, term: T) -> $name {
+                $name { query: self.query.push(term.into().to_string()) }
+            }
+
+            /// Combines the next clause with the previous ones using `AND` (the default).
+            pub fn and(self) -> $name {
+                $name { query: self.query.operator(Operator::And) }
+            }
+
+            /// Combines the next clause with the previous ones using `OR`.
+            pub fn or(self) -> $name {
+                $name { query: self.query.operator(Operator::Or) }
+            }
+
+            /// Excludes the results matching the next clause.
+            pub fn not(self) -> $name {
+                $name { query: self.query.operator(Operator::Not) }
+            }
+
+            /// Adds a whole query as a single, parenthesized clause. An empty query is ignored.
+            pub fn group(self, other: $name) -> $name {
+                if other.query.clauses.is_empty() {
+                    return self;
+                }
+                $name { query: self.query.push(format!("({})", other)) }
+            }
+        }
+
+        impl Default for $name {
+            fn default() -> $name {
+                $name::new()
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                self.query.fmt(f)
+            }
+        }
+
+        impl SearchQuery for $name {
+            type Entity = $entity;
+        }
+    }
+}
+
+search_query!(
+    /// A search query for artists.
+    ArtistQuery, ::artist::Artist, {
+    name => "artist",
+    name_accent => "artistaccent",
+    alias => "alias",
+    sort_name => "sortname",
+    id => "arid",
+    area => "area",
+    begin_area => "beginarea",
+    end_area => "endarea",
+    begin => "begin",
+    end => "end",
+    ended => "ended",
+    comment => "comment",
+    country => "country",
+    gender => "gender",
+    ipi => "ipi",
+    isni => "isni",
+    tag => "tag",
+    type_ => "type"
+});
+
+search_query!(
+    /// A search query for release groups.
+    ReleaseGroupQuery, ::release_group::ReleaseGroup, {
+    title => "releasegroup",
+    title_accent => "releasegroupaccent",
+    id => "rgid",
+    artist => "artist",
+    artist_name => "artistname",
+    artist_id => "arid",
+    credit_name => "creditname",
+    comment => "comment",
+    first_release_date => "firstreleasedate",
+    primary_type => "primarytype",
+    secondary_type => "secondarytype",
+    release => "release",
+    release_id => "reid",
+    releases => "releases",
+    status => "status",
+    tag => "tag"
+});
+
+search_query!(
+    /// A search query for releases.
+    ReleaseQuery, ::release::Release, {
+    title => "release",
+    title_accent => "releaseaccent",
+    id => "reid",
+    artist => "artist",
+    artist_name => "artistname",
+    artist_id => "arid",
+    credit_name => "creditname",
+    asin => "asin",
+    barcode => "barcode",
+    catalog_number => "catno",
+    comment => "comment",
+    country => "country",
+    date => "date",
+    format => "format",
+    label => "label",
+    label_id => "laid",
+    language => "lang",
+    media => "mediums",
+    primary_type => "primarytype",
+    secondary_type => "secondarytype",
+    release_group_id => "rgid",
+    script => "script",
+    status => "status",
+    tag => "tag",
+    tracks => "tracks"
+});
+
+search_query!(
+    /// A search query for recordings.
+    RecordingQuery, ::recording::Recording, {
+    title => "recording",
+    title_accent => "recordingaccent",
+    id => "rid",
+    alias => "alias",
+    artist => "artist",
+    artist_name => "artistname",
+    artist_id => "arid",
+    credit_name => "creditname",
+    comment => "comment",
+    country => "country",
+    date => "date",
+    duration => "dur",
+    first_release_date => "firstreleasedate",
+    format => "format",
+    isrc => "isrc",
+    number => "number",
+    position => "position",
+    primary_type => "primarytype",
+    release => "release",
+    release_id => "reid",
+    release_group_id => "rgid",
+    secondary_type => "secondarytype",
+    status => "status",
+    tag => "tag",
+    track_id => "tid",
+    video => "video"
+});
+
+search_query!(
+    /// A search query for labels.
+    LabelQuery, ::label::Label, {
+    name => "label",
+    name_accent => "labelaccent",
+    id => "laid",
+    alias => "alias",
+    sort_name => "sortname",
+    area => "area",
+    begin => "begin",
+    end => "end",
+    ended => "ended",
+    code => "code",
+    comment => "comment",
+    country => "country",
+    ipi => "ipi",
+    isni => "isni",
+    tag => "tag",
+    type_ => "type"
+});
+
+search_query!(
+    /// A search query for works.
+    WorkQuery, ::work::Work, {
+    title => "work",
+    title_accent => "workaccent",
+    id => "wid",
+    alias => "alias",
+    artist => "artist",
+    artist_id => "arid",
+    comment => "comment",
+    iswc => "iswc",
+    language => "lang",
+    recording => "recording",
+    recording_id => "rid",
+    tag => "tag",
+    type_ => "type"
+});
+
+search_query!(
+    /// A search query for areas.
+    AreaQuery, ::area::Area, {
+    name => "area",
+    id => "aid",
+    alias => "alias",
+    sort_name => "sortname",
+    begin => "begin",
+    end => "end",
+    ended => "ended",
+    comment => "comment",
+    iso => "iso",
+    iso1 => "iso1",
+    iso2 => "iso2",
+    iso3 => "iso3",
+    tag => "tag",
+    type_ => "type"
+});
+
+search_query!(
+    /// A search query for places.
+    PlaceQuery, ::place::Place, {
+    name => "place",
+    name_accent => "placeaccent",
+    id => "pid",
+    alias => "alias",
+    address => "address",
+    area => "area",
+    begin => "begin",
+    end => "end",
+    ended => "ended",
+    comment => "comment",
+    latitude => "lat",
+    longitude => "long",
+    type_ => "type"
+});
+
+search_query!(
+    /// A search query for events.
+    EventQuery, ::event::Event, {
+    name => "event",
+    name_accent => "eventaccent",
+    id => "eid",
+    alias => "alias",
+    area => "area",
+    area_id => "aid",
+    artist => "artist",
+    artist_id => "arid",
+    place => "place",
+    place_id => "pid",
+    begin => "begin",
+    end => "end",
+    ended => "ended",
+    comment => "comment",
+    tag => "tag",
+    type_ => "type"
+});
+
+search_query!(
+    /// A search query for instruments.
+    InstrumentQuery, ::instrument::Instrument, {
+    name => "instrument",
+    name_accent => "instrumentaccent",
+    id => "iid",
+    alias => "alias",
+    comment => "comment",
+    description => "description",
+    tag => "tag",
+    type_ => "type"
+});
+
+search_query!(
+    /// A search query for series.
+    SeriesQuery, ::series::Series, {
+    name => "series",
+    name_accent => "seriesaccent",
+    id => "sid",
+    alias => "alias",
+    comment => "comment",
+    ordering_attribute => "orderingattribute",
+    tag => "tag",
+    type_ => "type"
+});
+
+search_query!(
+    /// A search query for URLs.
+    UrlQuery, ::url::Url, {
+    resource => "url",
+    id => "uid",
+    relation_type => "relationtype",
+    target_id => "targetid",
+    target_type => "targettype"
+});
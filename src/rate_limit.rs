@@ -0,0 +1,95 @@
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+use liburl::Url;
+
+/// A token bucket limiting how often requests are sent to the web service.
+///
+/// MusicBrainz allows an average of one request per second per client, and
+/// blocks clients exceeding it. The limiter is shared by every clone of a
+/// `MusicBrainz` client, including across threads.
+#[derive(Debug)]
+pub struct RateLimiter {
+    capacity: f64,
+    /// Tokens added to the bucket per second.
+    rate: f64,
+    bucket: Mutex<Bucket>
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    last_refill: Instant
+}
+
+impl RateLimiter {
+    /// Allows `requests` requests every `period`, which may all be sent at once.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use musicbrainz::rate_limit::RateLimiter;
+    /// # use std::time::Duration;
+    /// // The limit of musicbrainz.org: one request per second.
+    /// let limiter = RateLimiter::new(1, Duration::from_secs(1));
+    /// ```
+    pub fn new(requests: u32, period: Duration) -> RateLimiter {
+        let capacity = requests.max(1) as f64;
+        let period = period.as_secs() as f64 + period.subsec_nanos() as f64 / 1e9;
+
+        RateLimiter {
+            capacity: capacity,
+            rate: capacity / period,
+            bucket: Mutex::new(Bucket {
+                tokens: capacity,
+                last_refill: Instant::now()
+            })
+        }
+    }
+
+    /// Returns the limiter matching the policy of the server at `base_url`: one
+    /// request per second for musicbrainz.org, and none for self-hosted mirrors.
+    pub fn default_for(base_url: &str) -> Option<RateLimiter> {
+        let host = match Url::parse(base_url) {
+            Ok(url) => url.host_str().map(|host| host.to_lowercase()),
+            Err(_) => None
+        };
+
+        match host {
+            Some(ref host) if host == "musicbrainz.org" || host.ends_with(".musicbrainz.org") => {
+                Some(RateLimiter::new(1, Duration::from_secs(1)))
+            },
+            _ => None
+        }
+    }
+
+    /// Blocks the current thread until a request may be sent.
+    pub fn acquire(&self) {
+        while let Err(wait) = self.try_acquire() {
+            thread::sleep(wait);
+        }
+    }
+
+    /// Takes a token if one is available, or else returns how long to wait before
+    /// trying again. Used by clients which cannot block the current thread.
+    pub fn try_acquire(&self) -> Result<(), Duration> {
+        let mut bucket = match self.bucket.lock() {
+            Ok(bucket) => bucket,
+            Err(poisoned) => poisoned.into_inner()
+        };
+
+        let now = Instant::now();
+        let elapsed = now.duration_since(bucket.last_refill);
+        let elapsed = elapsed.as_secs() as f64 + elapsed.subsec_nanos() as f64 / 1e9;
+        bucket.tokens = (bucket.tokens + elapsed * self.rate).min(self.capacity);
+        bucket.last_refill = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            return Ok(());
+        }
+
+        let wait = (1.0 - bucket.tokens) / self.rate;
+        Err(Duration::from_millis((wait * 1000.0).ceil() as u64))
+    }
+}
@@ -0,0 +1,160 @@
+use uuid::Uuid;
+use artist_credit::ArtistCredit;
+use std::collections::HashMap;
+use std::fmt;
+use traits::{Entity, Browse};
+use browse::{LinkedEntity, Page};
+use error::Error;
+use parse;
+use include::{Include, Request};
+use paginated::Paginated;
+use search::SearchResult;
+use json::JsonValue;
+use relationship::Relationship;
+#[cfg(feature = "async")]
+use traits::AsyncEntity;
+#[cfg(feature = "async")]
+use async_client::{AsyncMusicBrainz, ApiFuture};
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub struct Recording {
+    #[cfg_attr(feature = "serde", serde(with = "::serde_impls::uuid"))]
+    pub id: Uuid,
+    pub title: String,
+    /// Length of the recording in milliseconds, if known.
+    pub length: Option<u32>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub video: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub isrcs: Vec<String>,
+    #[cfg_attr(feature = "serde", serde(default, with = "::serde_impls::nullable"))]
+    pub disambiguation: String,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub artist_credit: ArtistCredit,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub relations: Vec<Relationship>
+}
+
+impl Recording {
+    pub fn new(id: Uuid, title: String, length: Option<u32>, video: bool, isrcs: Vec<String>, disambiguation: String, artist_credit: ArtistCredit) -> Recording {
+        Recording {
+            id: id,
+            title: title,
+            length: length,
+            video: video,
+            isrcs: isrcs,
+            disambiguation: disambiguation,
+            artist_credit: artist_credit,
+            relations: Vec::new()
+        }
+    }
+
+    pub fn empty() -> Recording {
+        Recording::new(
+            Uuid::nil(),
+            String::new(),
+            None,
+            false,
+            Vec::new(),
+            String::new(),
+            ArtistCredit::empty()
+        )
+    }
+
+    pub(crate) fn from_json(recording: &JsonValue) -> Result<Recording, Error> {
+        let id = parse::mbid(recording, "id")?;
+
+        let isrcs = parse::strings(recording, "isrcs")?;
+
+        let mut result = Recording::new(
+            id,
+            parse::string(recording, "title")?,
+            recording["length"].as_u32(),
+            recording["video"].as_bool().unwrap_or(false),
+            isrcs,
+            recording["disambiguation"].as_str().unwrap_or("").to_string(),
+            ArtistCredit::from_json(recording)?
+        );
+        result.relations = Relationship::from_json(recording)?;
+        Ok(result)
+    }
+}
+
+impl PartialEq for Recording {
+    fn eq(&self, other: &Recording) -> bool {
+        self.id == other.id && self.title == other.title
+    }
+}
+
+impl fmt::Display for Recording {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{title}", title=self.title)?;
+        writeln!(f, "Id: {id}", id=self.id)
+    }
+}
+
+/// Subqueries that can be included in a lookup request.
+const LOOKUP_INCLUDES: &'static [Include] = &[
+    Include::Aliases, Include::Annotation, Include::Tags, Include::Genres, Include::UserTags,
+    Include::UserGenres, Include::Ratings, Include::UserRatings, Include::Artists, Include::Releases,
+    Include::ReleaseGroups, Include::Isrcs, Include::ArtistCredits, Include::Media
+];
+
+/// Subqueries that can be included in a browse request.
+const BROWSE_INCLUDES: &'static [Include] = &[
+    Include::Aliases, Include::Annotation, Include::Tags, Include::Genres, Include::UserTags,
+    Include::UserGenres, Include::Ratings, Include::UserRatings, Include::ArtistCredits, Include::Isrcs
+];
+
+impl Entity for Recording {
+    fn search(&self, client: &super::MusicBrainz, params: &mut HashMap<&str, &str>) -> Result<Page<SearchResult<Self>>, Error> {
+        client.search("recording", "recordings", params, Recording::from_json)
+    }
+
+    fn lookup(&self, client: &super::MusicBrainz, entity_id: &Uuid, params: &mut HashMap<&str, &str>) -> Result<Self, Error> {
+        let recording_data = match client.get(&format!("recording/{id}", id=entity_id), params) {
+            Ok(x) => x,
+            Err(e) => return Err(e)
+        };
+
+        Recording::from_json(&recording_data)
+    }
+
+    fn search_all<'a>(&self, client: &'a super::MusicBrainz, params: &HashMap<&str, &str>) -> Paginated<'a, SearchResult<Self>> {
+        client.search_all("recording", "recordings", params, Recording::from_json)
+    }
+
+    fn includes(&self, request: Request) -> &'static [Include] {
+        match request {
+            Request::Lookup => LOOKUP_INCLUDES,
+            Request::Browse => BROWSE_INCLUDES,
+            _ => &[]
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncEntity for Recording {
+    fn search_async(&self, client: &AsyncMusicBrainz, params: &HashMap<&str, &str>) -> ApiFuture<Page<SearchResult<Self>>> {
+        client.search("recording", "recordings", params, Recording::from_json)
+    }
+
+    fn lookup_async(&self, client: &AsyncMusicBrainz, entity_id: &Uuid, params: &HashMap<&str, &str>) -> ApiFuture<Self> {
+        client.lookup(&format!("recording/{id}", id=entity_id), params, Recording::from_json)
+    }
+}
+
+/// Entities that can be linked to in a browse request.
+const BROWSE_BY: &'static [&'static str] = &["artist", "collection", "release", "work"];
+
+impl Browse for Recording {
+    fn browse(&self, client: &super::MusicBrainz, linked: &LinkedEntity, params: &mut HashMap<&str, &str>) -> Result<Page<Self>, Error> {
+        client.browse("recording", "recordings", BROWSE_BY, linked, params, Recording::from_json)
+    }
+
+    fn browse_all<'a>(&self, client: &'a super::MusicBrainz, linked: &LinkedEntity, params: &HashMap<&str, &str>) -> Paginated<'a, Self> {
+        client.browse_all("recording", "recordings", BROWSE_BY, linked, params, Recording::from_json)
+    }
+}
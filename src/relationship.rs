@@ -0,0 +1,184 @@
+use uuid::Uuid;
+use std::collections::HashMap;
+use enums::Direction;
+use partial_date::PartialDate;
+use error::Error;
+use parse;
+use json::JsonValue;
+use area::Area;
+use artist::Artist;
+use event::Event;
+use genre::Genre;
+use instrument::Instrument;
+use label::Label;
+use place::Place;
+use recording::Recording;
+use release::Release;
+use release_group::ReleaseGroup;
+use series::Series;
+use url::Url;
+use work::Work;
+
+/// A link between two entities, such as an artist being a member of a band, the
+/// producer of a recording, or the homepage of a label.
+///
+/// Relationships are only returned when the matching `*-rels` includes are requested,
+/// e.g. `Include::ArtistRels` and `Include::UrlRels`.
+///
+/// # Example
+///
+/// ```no_run
+/// # use musicbrainz::*;
+/// # use musicbrainz::include::Include;
+/// # use musicbrainz::relationship::Target;
+/// let musicbrainz = MusicBrainz::new();
+///
+/// let artist_id = Uuid::parse_str("4a00ec9d-c635-463a-8cd4-eb61725f0c60").expect("failed to parse artist ID as Uuid");
+/// let artist = musicbrainz.artist().lookup_with(&musicbrainz, &artist_id, &[Include::UrlRels]).unwrap();
+///
+/// for relation in &artist.relations {
+///     if let Target::Url(ref url) = relation.target {
+///         println!("{}: {}", relation.relation_type, url.resource);
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "::serde_impls::RelationshipJson", into = "::serde_impls::RelationshipJson"))]
+pub struct Relationship {
+    /// The name of the relationship type, e.g. `member of band` or `producer`.
+    pub relation_type: String,
+    pub type_id: Uuid,
+    pub direction: Direction,
+    /// The entity at the other end of the relationship.
+    pub target: Target,
+    /// The names of the attributes of the relationship, e.g. `guest` or `lead vocals`.
+    pub attributes: Vec<String>,
+    /// The values given to some of the attributes, e.g. `number` for a part of a series.
+    pub attribute_values: HashMap<String, String>,
+    pub begin: PartialDate,
+    pub end: PartialDate,
+    pub ended: bool,
+    /// The position of the relationship among those of the same type, e.g. the parts of a series.
+    pub ordering_key: Option<u32>
+}
+
+/// The entity a relationship links to.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Target {
+    Area(Area),
+    Artist(Artist),
+    Event(Event),
+    Genre(Genre),
+    Instrument(Instrument),
+    Label(Label),
+    Place(Place),
+    Recording(Recording),
+    Release(Release),
+    ReleaseGroup(ReleaseGroup),
+    Series(Series),
+    Url(Url),
+    Work(Work)
+}
+
+impl Target {
+    /// The type of the target as named by the web service, e.g. `release_group`.
+    pub fn target_type(&self) -> &'static str {
+        match *self {
+            Target::Area(_) => "area",
+            Target::Artist(_) => "artist",
+            Target::Event(_) => "event",
+            Target::Genre(_) => "genre",
+            Target::Instrument(_) => "instrument",
+            Target::Label(_) => "label",
+            Target::Place(_) => "place",
+            Target::Recording(_) => "recording",
+            Target::Release(_) => "release",
+            Target::ReleaseGroup(_) => "release_group",
+            Target::Series(_) => "series",
+            Target::Url(_) => "url",
+            Target::Work(_) => "work"
+        }
+    }
+
+    /// The MBID of the target.
+    pub fn id(&self) -> Uuid {
+        match *self {
+            Target::Area(ref x) => x.id,
+            Target::Artist(ref x) => x.id,
+            Target::Event(ref x) => x.id,
+            Target::Genre(ref x) => x.id,
+            Target::Instrument(ref x) => x.id,
+            Target::Label(ref x) => x.id,
+            Target::Place(ref x) => x.id,
+            Target::Recording(ref x) => x.id,
+            Target::Release(ref x) => x.id,
+            Target::ReleaseGroup(ref x) => x.id,
+            Target::Series(ref x) => x.id,
+            Target::Url(ref x) => x.id,
+            Target::Work(ref x) => x.id
+        }
+    }
+
+    fn from_json(relation: &JsonValue) -> Result<Target, Error> {
+        let target_type = match relation["target-type"].as_str() {
+            Some(x) => x,
+            None if relation["target-type"].is_null() => return Err(Error::MissingField("target-type".to_string())),
+            None => return Err(Error::InvalidType { path: "target-type".to_string(), expected: "string" })
+        };
+
+        // The target is stored under its type, which the XML format spells with a hyphen.
+        let field = if relation[target_type].is_null() { target_type.replace('_', "-") } else { target_type.to_string() };
+        let target = &relation[field.as_str()];
+
+        let result = match target_type {
+            "area" => Area::from_json(target).map(Target::Area),
+            "artist" => Artist::from_json(target).map(Target::Artist),
+            "event" => Event::from_json(target).map(Target::Event),
+            "genre" => Genre::from_json(target).map(Target::Genre),
+            "instrument" => Instrument::from_json(target).map(Target::Instrument),
+            "label" => Label::from_json(target).map(Target::Label),
+            "place" => Place::from_json(target).map(Target::Place),
+            "recording" => Recording::from_json(target).map(Target::Recording),
+            "release" => Release::from_json(target).map(Target::Release),
+            "release_group" => ReleaseGroup::from_json(target).map(Target::ReleaseGroup),
+            "series" => Series::from_json(target).map(Target::Series),
+            "url" => Url::from_json(target).map(Target::Url),
+            "work" => Work::from_json(target).map(Target::Work),
+            _ => return Err(Error::UnknownValue { path: "target-type".to_string(), value: target_type.to_string() })
+        };
+
+        match result {
+            Ok(x) => Ok(x),
+            Err(e) => Err(e.within(&field))
+        }
+    }
+}
+
+impl Relationship {
+    /// Parses the `relations` array of a web service response, which is absent
+    /// unless relationships were requested.
+    pub fn from_json(data: &JsonValue) -> Result<Vec<Relationship>, Error> {
+        parse::list(data, "relations", |relation| {
+            let type_id = if relation["type-id"].is_null() { Uuid::nil() } else { parse::mbid(relation, "type-id")? };
+
+            let mut attribute_values = HashMap::new();
+            for (name, value) in relation["attribute-values"].entries() {
+                attribute_values.insert(name.to_string(), value.to_string());
+            }
+
+            Ok(Relationship {
+                relation_type: parse::string(relation, "type")?,
+                type_id: type_id,
+                direction: parse::value(relation, "direction", Direction::Forward)?,
+                target: Target::from_json(relation)?,
+                attributes: parse::strings(relation, "attributes")?,
+                attribute_values: attribute_values,
+                begin: parse::value(relation, "begin", PartialDate::empty())?,
+                end: parse::value(relation, "end", PartialDate::empty())?,
+                ended: relation["ended"].as_bool().unwrap_or(false),
+                ordering_key: relation["ordering-key"].as_u32()
+            })
+        })
+    }
+}
@@ -0,0 +1,262 @@
+use uuid::Uuid;
+use enums::ReleaseStatus;
+use std::collections::HashMap;
+use std::fmt;
+use traits::{Entity, Browse};
+use browse::{LinkedEntity, Page};
+use error::Error;
+use parse;
+use include::{Include, Request};
+use paginated::Paginated;
+use search::SearchResult;
+use json::JsonValue;
+use relationship::Relationship;
+use medium::Medium;
+use artist_credit::ArtistCredit;
+use partial_date::PartialDate;
+#[cfg(feature = "async")]
+use traits::AsyncEntity;
+#[cfg(feature = "async")]
+use async_client::{AsyncMusicBrainz, ApiFuture};
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub struct Release {
+    #[cfg_attr(feature = "serde", serde(with = "::serde_impls::uuid"))]
+    pub id: Uuid,
+    pub title: String,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub artist_credit: ArtistCredit,
+    #[cfg_attr(feature = "serde", serde(default, with = "::serde_impls::known"))]
+    pub status: Option<ReleaseStatus>,
+    #[cfg_attr(feature = "serde", serde(default, with = "::serde_impls::nullable"))]
+    pub packaging: String,
+    #[cfg_attr(feature = "serde", serde(default, with = "::serde_impls::nullable"))]
+    pub barcode: String,
+    #[cfg_attr(feature = "serde", serde(default, with = "::serde_impls::nullable"))]
+    pub country: String,
+    #[cfg_attr(feature = "serde", serde(default, with = "::serde_impls::nullable"))]
+    pub date: PartialDate,
+    #[cfg_attr(feature = "serde", serde(default, with = "::serde_impls::nullable"))]
+    pub disambiguation: String,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub text_representation: TextRepresentation,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub release_events: Vec<ReleaseEvent>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub label_info: Vec<LabelInfo>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub media: Vec<Medium>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub relations: Vec<Relationship>
+}
+
+/// The language and script used for the titles and track listing of a release.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub struct TextRepresentation {
+    /// ISO 639-3 code of the language, e.g. `eng`.
+    #[cfg_attr(feature = "serde", serde(default, with = "::serde_impls::nullable"))]
+    pub language: String,
+    /// ISO 15924 code of the script, e.g. `Latn`.
+    #[cfg_attr(feature = "serde", serde(default, with = "::serde_impls::nullable"))]
+    pub script: String
+}
+
+/// A date and country in which a release was issued.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub struct ReleaseEvent {
+    #[cfg_attr(feature = "serde", serde(default, with = "::serde_impls::nullable"))]
+    pub date: PartialDate,
+    #[cfg_attr(feature = "serde", serde(default, with = "::serde_impls::name"))]
+    pub area: String
+}
+
+/// A label a release was issued on, along with the catalog number used by it.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "::serde_impls::LabelInfoJson", into = "::serde_impls::LabelInfoJson"))]
+pub struct LabelInfo {
+    pub catalog_number: String,
+    pub label: Uuid,
+    pub label_name: String
+}
+
+impl Release {
+    pub fn new(id: Uuid, title: String, artist_credit: ArtistCredit, status: Option<ReleaseStatus>, packaging: String, barcode: String,
+               country: String, date: PartialDate, disambiguation: String, text_representation: TextRepresentation,
+               release_events: Vec<ReleaseEvent>, label_info: Vec<LabelInfo>, media: Vec<Medium>) -> Release {
+        Release {
+            id: id,
+            title: title,
+            artist_credit: artist_credit,
+            status: status,
+            packaging: packaging,
+            barcode: barcode,
+            country: country,
+            date: date,
+            disambiguation: disambiguation,
+            text_representation: text_representation,
+            release_events: release_events,
+            label_info: label_info,
+            media: media,
+            relations: Vec::new()
+        }
+    }
+
+    pub fn empty() -> Release {
+        Release::new(
+            Uuid::nil(),
+            String::new(),
+            ArtistCredit::empty(),
+            None,
+            String::new(),
+            String::new(),
+            String::new(),
+            PartialDate::empty(),
+            String::new(),
+            TextRepresentation { language: String::new(), script: String::new() },
+            Vec::new(),
+            Vec::new(),
+            Vec::new()
+        )
+    }
+
+    pub(crate) fn from_json(release: &JsonValue) -> Result<Release, Error> {
+        let id = parse::mbid(release, "id")?;
+
+        let status = parse::optional::<ReleaseStatus>(release, "status")?;
+
+        let text_representation = TextRepresentation {
+            language: release["text-representation"]["language"].as_str().unwrap_or("").to_string(),
+            script: release["text-representation"]["script"].as_str().unwrap_or("").to_string()
+        };
+
+        let mut release_events: Vec<ReleaseEvent> = Vec::new();
+        for (i, event) in release["release-events"].members().enumerate() {
+            release_events.push(ReleaseEvent {
+                date: match parse::value(event, "date", PartialDate::empty()) {
+                    Ok(x) => x,
+                    Err(e) => return Err(e.within(&format!("release-events[{}]", i)))
+                },
+                area: event["area"]["name"].as_str().unwrap_or("").to_string()
+            });
+        }
+
+        let label_info = parse::list(release, "label-info", |info| {
+            // Catalog numbers may be given without a label.
+            let label = if info["label"].is_null() {
+                Uuid::nil()
+            } else {
+                parse::mbid(&info["label"], "id").map_err(|e| e.within("label"))?
+            };
+
+            Ok(LabelInfo {
+                catalog_number: info["catalog-number"].as_str().unwrap_or("").to_string(),
+                label: label,
+                label_name: info["label"]["name"].as_str().unwrap_or("").to_string()
+            })
+        })?;
+
+        let mut result = Release::new(
+            id,
+            parse::string(release, "title")?,
+            ArtistCredit::from_json(release)?,
+            status,
+            release["packaging"].as_str().unwrap_or("").to_string(),
+            release["barcode"].as_str().unwrap_or("").to_string(),
+            release["country"].as_str().unwrap_or("").to_string(),
+            parse::value(release, "date", PartialDate::empty())?,
+            release["disambiguation"].as_str().unwrap_or("").to_string(),
+            text_representation,
+            release_events,
+            label_info,
+            parse::list(release, "media", Medium::from_json)?
+        );
+        result.relations = Relationship::from_json(release)?;
+        Ok(result)
+    }
+}
+
+impl PartialEq for Release {
+    fn eq(&self, other: &Release) -> bool {
+        self.id == other.id && self.title == other.title
+    }
+}
+
+impl fmt::Display for Release {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{title} ({date})", title=self.title, date=self.date)?;
+        writeln!(f, "Id: {id}", id=self.id)
+    }
+}
+
+/// Subqueries that can be included in a lookup request.
+const LOOKUP_INCLUDES: &'static [Include] = &[
+    Include::Aliases, Include::Annotation, Include::Tags, Include::Genres, Include::UserTags,
+    Include::UserGenres, Include::Artists, Include::Collections, Include::UserCollections, Include::Labels,
+    Include::Recordings, Include::ReleaseGroups, Include::Media, Include::ArtistCredits, Include::DiscIds,
+    Include::Isrcs, Include::RecordingLevelRels, Include::ReleaseGroupLevelRels, Include::WorkLevelRels
+];
+
+/// Subqueries that can be included in a browse request.
+const BROWSE_INCLUDES: &'static [Include] = &[
+    Include::Aliases, Include::Annotation, Include::Tags, Include::Genres, Include::UserTags,
+    Include::UserGenres, Include::ArtistCredits, Include::Labels, Include::Recordings,
+    Include::ReleaseGroups, Include::Media, Include::DiscIds, Include::Isrcs
+];
+
+impl Entity for Release {
+    fn search(&self, client: &super::MusicBrainz, params: &mut HashMap<&str, &str>) -> Result<Page<SearchResult<Self>>, Error> {
+        client.search("release", "releases", params, Release::from_json)
+    }
+
+    fn lookup(&self, client: &super::MusicBrainz, entity_id: &Uuid, params: &mut HashMap<&str, &str>) -> Result<Self, Error> {
+        let release_data = match client.get(&format!("release/{id}", id=entity_id), params) {
+            Ok(x) => x,
+            Err(e) => return Err(e)
+        };
+
+        Release::from_json(&release_data)
+    }
+
+    fn search_all<'a>(&self, client: &'a super::MusicBrainz, params: &HashMap<&str, &str>) -> Paginated<'a, SearchResult<Self>> {
+        client.search_all("release", "releases", params, Release::from_json)
+    }
+
+    fn includes(&self, request: Request) -> &'static [Include] {
+        match request {
+            Request::Lookup => LOOKUP_INCLUDES,
+            Request::Browse => BROWSE_INCLUDES,
+            _ => &[]
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncEntity for Release {
+    fn search_async(&self, client: &AsyncMusicBrainz, params: &HashMap<&str, &str>) -> ApiFuture<Page<SearchResult<Self>>> {
+        client.search("release", "releases", params, Release::from_json)
+    }
+
+    fn lookup_async(&self, client: &AsyncMusicBrainz, entity_id: &Uuid, params: &HashMap<&str, &str>) -> ApiFuture<Self> {
+        client.lookup(&format!("release/{id}", id=entity_id), params, Release::from_json)
+    }
+}
+
+/// Entities that can be linked to in a browse request.
+const BROWSE_BY: &'static [&'static str] = &["area", "artist", "collection", "label", "recording", "release-group", "track_artist"];
+
+impl Browse for Release {
+    fn browse(&self, client: &super::MusicBrainz, linked: &LinkedEntity, params: &mut HashMap<&str, &str>) -> Result<Page<Self>, Error> {
+        client.browse("release", "releases", BROWSE_BY, linked, params, Release::from_json)
+    }
+
+    fn browse_all<'a>(&self, client: &'a super::MusicBrainz, linked: &LinkedEntity, params: &HashMap<&str, &str>) -> Paginated<'a, Self> {
+        client.browse_all("release", "releases", BROWSE_BY, linked, params, Release::from_json)
+    }
+}
@@ -2,35 +2,87 @@ use uuid::Uuid;
 use enums::*;
 use std::collections::HashMap;
 use std::fmt;
-use traits::Entity;
+use traits::{Entity, Browse};
+use browse::{LinkedEntity, Page};
 use error::Error;
+use parse;
+use include::{Include, Request};
+use paginated::Paginated;
+use search::SearchResult;
+use json::JsonValue;
+use artist_credit::ArtistCredit;
+use partial_date::PartialDate;
+use relationship::Relationship;
+#[cfg(feature = "async")]
+use traits::AsyncEntity;
+#[cfg(feature = "async")]
+use async_client::{AsyncMusicBrainz, ApiFuture};
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub struct ReleaseGroup {
     pub title: String,
-    pub release_date: String,
+    #[cfg_attr(feature = "serde", serde(rename = "first-release-date", default, with = "::serde_impls::nullable"))]
+    pub release_date: PartialDate,
+    #[cfg_attr(feature = "serde", serde(with = "::serde_impls::uuid"))]
     pub id: Uuid,
-    pub artist: Uuid,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub artist_credit: ArtistCredit,
+    #[cfg_attr(feature = "serde", serde(default, with = "::serde_impls::nullable"))]
     pub primary_type: AlbumType,
-    pub secondary_types: Vec<AlbumType>
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub secondary_types: Vec<AlbumType>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub relations: Vec<Relationship>
 }
 
 impl ReleaseGroup {
-    pub fn new(title: String, release_date: String, id: Uuid, artist: Uuid, primary_type: AlbumType, secondary_types: Vec<AlbumType>) -> ReleaseGroup {
+    pub fn new(title: String, release_date: PartialDate, id: Uuid, artist_credit: ArtistCredit, primary_type: AlbumType, secondary_types: Vec<AlbumType>) -> ReleaseGroup {
         ReleaseGroup {
             title: title,
             release_date: release_date,
             id: id,
-            artist: artist,
+            artist_credit: artist_credit,
             primary_type: primary_type,
-            secondary_types: secondary_types
+            secondary_types: secondary_types,
+            relations: Vec::new()
         }
     }
+
+    pub fn empty() -> ReleaseGroup {
+        ReleaseGroup::new(
+            String::new(),
+            PartialDate::empty(),
+            Uuid::nil(),
+            ArtistCredit::empty(),
+            AlbumType::Other,
+            Vec::new()
+        )
+    }
+
+    pub(crate) fn from_json(album: &JsonValue) -> Result<ReleaseGroup, Error> {
+        let album_type = parse::value(album, "primary-type", AlbumType::Other)?;
+        let secondary_types = parse::values(album, "secondary-types")?;
+
+        let album_id = parse::mbid(album, "id")?;
+
+        let mut result = ReleaseGroup::new(
+            parse::string(album, "title")?,
+            parse::value(album, "first-release-date", PartialDate::empty())?,
+            album_id,
+            ArtistCredit::from_json(album)?,
+            album_type,
+            secondary_types
+        );
+        result.relations = Relationship::from_json(album)?;
+        Ok(result)
+    }
 }
 
 impl PartialEq for ReleaseGroup {
     fn eq(&self, other: &ReleaseGroup) -> bool {
-        self.id == other.id && self.artist == other.artist
+        self.id == other.id && self.artist_credit == other.artist_credit
     }
 }
 
@@ -41,123 +93,66 @@ impl fmt::Display for ReleaseGroup {
     }
 }
 
-impl Entity for ReleaseGroup {
-    fn search(&self, client: &super::MusicBrainz, params: &mut HashMap<&str, &str>) -> Result<Vec<Self>, Error> {
-        let data = match client.get("release-group", params) {
-            Ok(x) => x,
-            Err(e) => return Err(Error::ParseJson(e))
-        };
-
-        let count = data["count"].as_i32().unwrap();
-        let mut results: Vec<ReleaseGroup> = Vec::new();
+/// Subqueries that can be included in a lookup request.
+const LOOKUP_INCLUDES: &'static [Include] = &[
+    Include::Aliases, Include::Annotation, Include::Tags, Include::Genres, Include::UserTags,
+    Include::UserGenres, Include::Ratings, Include::UserRatings, Include::Artists, Include::Releases,
+    Include::Media, Include::DiscIds, Include::ArtistCredits
+];
 
-        if count == 0 {
-            return Ok(results);
-        }
+/// Subqueries that can be included in a browse request.
+const BROWSE_INCLUDES: &'static [Include] = &[
+    Include::Aliases, Include::Annotation, Include::Tags, Include::Genres, Include::UserTags,
+    Include::UserGenres, Include::Ratings, Include::UserRatings, Include::ArtistCredits
+];
 
-        let albums = &data["release-groups"];
-
-        for album in albums.members() {
-            if album["score"] == "100" {
-                let id = match album["id"].as_str() {
-                    Some(x) => {
-                        match Uuid::parse_str(x) {
-                            Ok(y) => y,
-                            Err(e) => return Err(Error::ParseUuid(e))
-                        }
-                    },
-                    None => return Err(Error::AsSlice)
-                };
-
-                let secondary_types: Vec<AlbumType> = Vec::new();
-                let artist_credits = &album["artist-credit"];
-                let artist_id = match artist_credits[0]["artist"]["id"].as_str() {
-                    Some(x) => {
-                        match Uuid::parse_str(x) {
-                            Ok(y) => y,
-                            Err(e) => return Err(Error::ParseUuid(e))
-                        }
-                    },
-                    None => return Err(Error::AsSlice)
-                };
-
-                let album_type = match album["primary_type"].as_str() {
-                    Some(x) => x.parse::<AlbumType>().unwrap(),
-                    None => return Err(Error::AsSlice)
-                };
-
-                results.push(ReleaseGroup::new(
-                    album["title"].to_string(),
-                    String::new(),
-                    id,
-                    artist_id,
-                    album_type,
-                    secondary_types
-                ))
-            }
-        }
-        Ok(results)
+impl Entity for ReleaseGroup {
+    fn search(&self, client: &super::MusicBrainz, params: &mut HashMap<&str, &str>) -> Result<Page<SearchResult<Self>>, Error> {
+        client.search("release-group", "release-groups", params, ReleaseGroup::from_json)
     }
 
     fn lookup(&self, client: &super::MusicBrainz, entity_id: &Uuid, params: &mut HashMap<&str, &str>) -> Result<Self, Error> {
-        let mut album_data = match client.get(&format!("release-group/{id}", id=entity_id), params) {
+        let album_data = match client.get(&format!("release-group/{id}", id=entity_id), params) {
             Ok(x) => x,
-            Err(e) => return Err(Error::ParseJson(e))
+            Err(e) => return Err(e)
         };
 
-        if !album_data["error"].is_null() {
-            let error_msg = album_data["error"].to_string();
-            return Err(Error::Http(error_msg));
-        }
+        ReleaseGroup::from_json(&album_data)
+    }
 
-        let album_type = match album_data["primary-type"].as_str() {
-            Some(x) => x.parse::<AlbumType>().unwrap(),
-            None => return Err(Error::AsSlice)
-        };
+    fn search_all<'a>(&self, client: &'a super::MusicBrainz, params: &HashMap<&str, &str>) -> Paginated<'a, SearchResult<Self>> {
+        client.search_all("release-group", "release-groups", params, ReleaseGroup::from_json)
+    }
 
-        let mut secondary_types: Vec<AlbumType> = Vec::new();
-        if !album_data["secondary-types"].is_null() && !album_data["secondary-types"].is_empty() {
-            for secondary_type in album_data["secondary-types"].members() {
-                secondary_types.push(
-                    match secondary_type.as_str() {
-                        Some(x) => x.parse::<AlbumType>().unwrap(),
-                        None => return Err(Error::AsSlice)
-                    }
-                );
-            }
+    fn includes(&self, request: Request) -> &'static [Include] {
+        match request {
+            Request::Lookup => LOOKUP_INCLUDES,
+            Request::Browse => BROWSE_INCLUDES,
+            _ => &[]
         }
+    }
+}
 
-        let mut artist: Uuid = Uuid::nil();
-        if !album_data["artist-credit"].is_null() && !album_data["artist-credit"].is_empty() {
-            let artist_credit = album_data["artist-credit"].pop();
-            artist = match artist_credit["artist"]["id"].as_str() {
-                Some(x) => {
-                    match Uuid::parse_str(x) {
-                        Ok(y) => y,
-                        Err(e) => return Err(Error::ParseUuid(e))
-                    }
-                },
-                None => return Err(Error::AsSlice)
-            };
-        }
+#[cfg(feature = "async")]
+impl AsyncEntity for ReleaseGroup {
+    fn search_async(&self, client: &AsyncMusicBrainz, params: &HashMap<&str, &str>) -> ApiFuture<Page<SearchResult<Self>>> {
+        client.search("release-group", "release-groups", params, ReleaseGroup::from_json)
+    }
 
-        let album_id = match album_data["id"].as_str() {
-            Some(x) => {
-                match Uuid::parse_str(x) {
-                    Ok(y) => y,
-                    Err(e) => return Err(Error::ParseUuid(e))
-                }
-            },
-            None => return Err(Error::AsSlice)
-        };
+    fn lookup_async(&self, client: &AsyncMusicBrainz, entity_id: &Uuid, params: &HashMap<&str, &str>) -> ApiFuture<Self> {
+        client.lookup(&format!("release-group/{id}", id=entity_id), params, ReleaseGroup::from_json)
+    }
+}
 
-        Ok(ReleaseGroup::new(
-            album_data["title"].to_string(),
-            album_data["first-release-date"].to_string(),
-            album_id,
-            artist,
-            album_type,
-            secondary_types
-        ))
+/// Entities that can be linked to in a browse request.
+const BROWSE_BY: &'static [&'static str] = &["artist", "collection", "release"];
+
+impl Browse for ReleaseGroup {
+    fn browse(&self, client: &super::MusicBrainz, linked: &LinkedEntity, params: &mut HashMap<&str, &str>) -> Result<Page<Self>, Error> {
+        client.browse("release-group", "release-groups", BROWSE_BY, linked, params, ReleaseGroup::from_json)
+    }
+
+    fn browse_all<'a>(&self, client: &'a super::MusicBrainz, linked: &LinkedEntity, params: &HashMap<&str, &str>) -> Paginated<'a, Self> {
+        client.browse_all("release-group", "release-groups", BROWSE_BY, linked, params, ReleaseGroup::from_json)
     }
 }
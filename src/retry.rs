@@ -0,0 +1,111 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use transport::Response;
+
+/// How requests are retried when MusicBrainz is unavailable or throttling the client.
+///
+/// Requests answered with `429 Too Many Requests` or any `5xx` status are retried
+/// after waiting for the delay given by the `Retry-After` header, either in seconds
+/// or as an HTTP date, or else for an exponentially increasing backoff. Once every
+/// attempt has failed, the request fails with `Error::Throttled`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// Maximum number of attempts, including the first one.
+    pub max_attempts: u32,
+    /// Delay before the first retry.
+    pub initial_backoff: Duration,
+    /// Factor the delay is multiplied by after each retry.
+    pub multiplier: u32,
+    /// Upper bound of the delay between two attempts.
+    pub max_backoff: Duration
+}
+
+impl RetryPolicy {
+    /// Never retries requests.
+    pub fn none() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 1,
+            ..RetryPolicy::default()
+        }
+    }
+
+    /// Whether a request answered with `status` should be attempted again.
+    pub fn should_retry(&self, status: u16) -> bool {
+        is_retryable(status)
+    }
+
+    /// The delay to wait for after the given failed attempt, starting at 1.
+    pub fn backoff(&self, attempt: u32, response: &Response) -> Duration {
+        if let Some(retry_after) = response.header("Retry-After").and_then(retry_after) {
+            return retry_after.min(self.max_backoff);
+        }
+
+        let mut delay = self.initial_backoff;
+        for _ in 1..attempt {
+            delay = delay * self.multiplier;
+            if delay >= self.max_backoff {
+                return self.max_backoff;
+            }
+        }
+        delay
+    }
+}
+
+impl Default for RetryPolicy {
+    /// Up to 5 attempts, waiting 1, 2, 4 then 8 seconds between them.
+    fn default() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 5,
+            initial_backoff: Duration::from_secs(1),
+            multiplier: 2,
+            max_backoff: Duration::from_secs(60)
+        }
+    }
+}
+
+/// Whether `status` means that MusicBrainz is unavailable or throttling the client.
+pub(crate) fn is_retryable(status: u16) -> bool {
+    status == 429 || (status >= 500 && status < 600)
+}
+
+/// Reads a `Retry-After` header, given either as a number of seconds or as an
+/// HTTP date such as `Sun, 06 Nov 1994 08:49:37 GMT`. Dates in the past yield no delay.
+fn retry_after(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = http_date(value)?;
+    Some(date.duration_since(SystemTime::now()).unwrap_or(Duration::from_secs(0)))
+}
+
+/// Parses an HTTP date in the preferred IMF-fixdate format, the only one MusicBrainz sends.
+fn http_date(value: &str) -> Option<SystemTime> {
+    let parts: Vec<&str> = value.split_whitespace().collect();
+    if parts.len() != 6 || !parts[0].ends_with(',') || parts[5] != "GMT" {
+        return None;
+    }
+
+    let day = parts[1].parse::<u64>().ok()?;
+    let months = ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"];
+    let month = months.iter().position(|x| *x == parts[2])? as u64 + 1;
+    let year = parts[3].parse::<u64>().ok()?;
+
+    let time: Vec<u64> = parts[4].split(':').filter_map(|x| x.parse::<u64>().ok()).collect();
+    if time.len() != 3 || year < 1970 || year > 9999 || day < 1 || day > 31 || time[0] > 23 || time[1] > 59 || time[2] > 60 {
+        return None;
+    }
+
+    // Days since the epoch of the civil date, counting years from March so that
+    // the leap day falls at the end of the year.
+    let (y, m) = if month <= 2 { (year - 1, month + 9) } else { (year, month - 3) };
+    // Overflows cannot happen for years up to 9999, but a malformed header must
+    // never panic, so every step is checked regardless.
+    let days = y.checked_mul(365)?
+        .checked_add(y / 4 - y / 100 + y / 400)?
+        .checked_add((153 * m + 2) / 5 + day - 1)?
+        .checked_sub(719468)?;
+
+    let seconds = days.checked_mul(86400)?.checked_add(time[0] * 3600 + time[1] * 60 + time[2])?;
+    UNIX_EPOCH.checked_add(Duration::from_secs(seconds))
+}
@@ -0,0 +1,32 @@
+use error::Error;
+use json::JsonValue;
+
+/// A single hit of a search request, along with its relevance score.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SearchResult<T> {
+    /// How well the entity matches the search query, from 0 to 100.
+    pub score: u8,
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub entity: T
+}
+
+impl<T> SearchResult<T> {
+    /// Parses a search hit, using `parse` to read the entity itself.
+    pub fn from_json<F>(hit: &JsonValue, parse: F) -> Result<SearchResult<T>, Error>
+        where F: Fn(&JsonValue) -> Result<T, Error>
+    {
+        Ok(SearchResult {
+            score: score(hit).unwrap_or(0),
+            entity: parse(hit)?
+        })
+    }
+}
+
+/// Reads the score of a search hit, which older servers return as a string.
+pub fn score(hit: &JsonValue) -> Option<u8> {
+    match hit["score"].as_u8() {
+        Some(x) => Some(x),
+        None => hit["score"].as_str().and_then(|x| x.parse::<u8>().ok())
+    }
+}
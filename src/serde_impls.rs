@@ -0,0 +1,380 @@
+//! Field adapters used by the `serde` implementations of the entities, mapping
+//! fields to the JSON returned by the web service where the two differ.
+//!
+//! Only compiled with the `serde` feature.
+
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use serde::{Serialize, Deserialize};
+use release::{Release, LabelInfo};
+use relationship::{Relationship, Target};
+use enums::Direction;
+use partial_date::PartialDate;
+use area::Area;
+use artist::Artist;
+use event::Event;
+use genre::Genre;
+use instrument::Instrument;
+use label::Label;
+use place::Place;
+use recording::Recording;
+use release_group::ReleaseGroup;
+use series::Series;
+use url::Url;
+use work::Work;
+
+/// An MBID, as a hyphenated string.
+pub mod uuid {
+    use serde::{Serialize, Serializer, Deserialize, Deserializer};
+    use serde::de::Error;
+    use uuid::Uuid;
+
+    pub fn serialize<S: Serializer>(id: &Uuid, serializer: S) -> Result<S::Ok, S::Error> {
+        id.hyphenated().to_string().serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Uuid, D::Error> {
+        let id = String::deserialize(deserializer)?;
+        Uuid::parse_str(&id).map_err(|e| D::Error::custom(format!("invalid MBID '{}': {:?}", id, e)))
+    }
+}
+
+/// A value the web service may return as `null`, read as its default value.
+pub mod nullable {
+    use serde::{Serialize, Serializer, Deserialize, Deserializer};
+
+    pub fn serialize<T: Serialize, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        value.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+        where T: Deserialize<'de> + Default, D: Deserializer<'de>
+    {
+        Ok(Option::<T>::deserialize(deserializer)?.unwrap_or_default())
+    }
+}
+
+/// An optional enum, failing on values this library does not know about as
+/// `parse::optional` does.
+pub mod known {
+    use std::fmt::Display;
+    use std::str::FromStr;
+    use serde::{Serializer, Deserialize, Deserializer};
+    use serde::de::Error;
+
+    pub fn serialize<T: Display, S: Serializer>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error> {
+        match *value {
+            Some(ref x) => serializer.collect_str(x),
+            None => serializer.serialize_none()
+        }
+    }
+
+    pub fn deserialize<'de, T: FromStr, D: Deserializer<'de>>(deserializer: D) -> Result<Option<T>, D::Error> {
+        match Option::<String>::deserialize(deserializer)? {
+            Some(x) => match x.parse::<T>() {
+                Ok(y) => Ok(Some(y)),
+                Err(_) => Err(D::Error::custom(format!("unknown value '{}'", x)))
+            },
+            None => Ok(None)
+        }
+    }
+}
+
+/// A number the web service may return as a string, e.g. coordinates.
+pub mod number {
+    use serde::{Serialize, Serializer, Deserialize, Deserializer};
+    use serde::de::Error;
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Number {
+        Float(f64),
+        Text(String)
+    }
+
+    pub fn serialize<S: Serializer>(value: &f64, serializer: S) -> Result<S::Ok, S::Error> {
+        value.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<f64, D::Error> {
+        match Number::deserialize(deserializer)? {
+            Number::Float(x) => Ok(x),
+            Number::Text(x) => x.parse::<f64>().map_err(D::Error::custom)
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct Named {
+    #[serde(default, with = "nullable")]
+    name: String
+}
+
+/// The name of a linked entity, e.g. the area of a label, read from `{ "name": ... }`.
+pub mod name {
+    use serde::{Serialize, Serializer, Deserialize, Deserializer};
+    use super::Named;
+
+    pub fn serialize<S: Serializer>(name: &String, serializer: S) -> Result<S::Ok, S::Error> {
+        Named { name: name.clone() }.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+        Ok(Option::<Named>::deserialize(deserializer)?.map(|x| x.name).unwrap_or_default())
+    }
+}
+
+/// The names of a list of objects, e.g. tags or aliases, read from `[{ "name": ... }]`.
+pub mod names {
+    use serde::{Serializer, Deserialize, Deserializer};
+    use super::Named;
+
+    pub fn serialize<S: Serializer>(names: &Vec<String>, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(names.iter().map(|name| Named { name: name.clone() }))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<String>, D::Error> {
+        let named = Option::<Vec<Named>>::deserialize(deserializer)?.unwrap_or_default();
+        Ok(named.into_iter().map(|x| x.name).collect())
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct Identified<T> {
+    id: T
+}
+
+/// The string identifiers of a list of objects, e.g. disc IDs, read from `[{ "id": ... }]`.
+pub mod ids {
+    use serde::{Serializer, Deserialize, Deserializer};
+    use super::Identified;
+
+    pub fn serialize<S: Serializer>(ids: &Vec<String>, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(ids.iter().map(|id| Identified { id: id.clone() }))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<String>, D::Error> {
+        let identified = Option::<Vec<Identified<String>>>::deserialize(deserializer)?.unwrap_or_default();
+        Ok(identified.into_iter().map(|x| x.id).collect())
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct Linked {
+    #[serde(with = "uuid")]
+    id: ::uuid::Uuid
+}
+
+/// The MBID of a linked entity, e.g. the recording of a track, read from `{ "id": ... }`.
+///
+/// A missing entity is read as the nil UUID.
+pub mod linked_id {
+    use serde::{Serialize, Serializer, Deserialize, Deserializer};
+    use uuid::Uuid;
+    use super::Linked;
+
+    pub fn serialize<S: Serializer>(id: &Uuid, serializer: S) -> Result<S::Ok, S::Error> {
+        if id.is_nil() {
+            serializer.serialize_none()
+        } else {
+            Linked { id: *id }.serialize(serializer)
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Uuid, D::Error> {
+        Ok(Option::<Linked>::deserialize(deserializer)?.map(|x| x.id).unwrap_or(Uuid::nil()))
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct LinkedLabel {
+    #[serde(with = "uuid")]
+    id: ::uuid::Uuid,
+    #[serde(default, with = "nullable")]
+    name: String
+}
+
+/// An entry of the `label-info` of a release, whose label holds both the MBID and
+/// the name stored in `LabelInfo`.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct LabelInfoJson {
+    #[serde(default, with = "nullable")]
+    catalog_number: String,
+    #[serde(default)]
+    label: Option<LinkedLabel>
+}
+
+impl From<LabelInfoJson> for LabelInfo {
+    fn from(info: LabelInfoJson) -> LabelInfo {
+        let (label, label_name) = match info.label {
+            Some(label) => (label.id, label.name),
+            None => (::uuid::Uuid::nil(), String::new())
+        };
+
+        LabelInfo {
+            catalog_number: info.catalog_number,
+            label: label,
+            label_name: label_name
+        }
+    }
+}
+
+impl From<LabelInfo> for LabelInfoJson {
+    fn from(info: LabelInfo) -> LabelInfoJson {
+        let label = if info.label.is_nil() {
+            None
+        } else {
+            Some(LinkedLabel { id: info.label, name: info.label_name })
+        };
+
+        LabelInfoJson {
+            catalog_number: info.catalog_number,
+            label: label
+        }
+    }
+}
+
+/// A relationship as returned by the web service, whose target is stored under a
+/// field named after its `target-type`.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct RelationshipJson {
+    #[serde(rename = "type", default, with = "nullable")]
+    relation_type: String,
+    #[serde(default)]
+    type_id: Option<String>,
+    #[serde(default, with = "nullable")]
+    direction: Direction,
+    target_type: String,
+    #[serde(default, with = "nullable")]
+    attributes: Vec<String>,
+    #[serde(default, with = "nullable")]
+    attribute_values: HashMap<String, String>,
+    #[serde(default, with = "nullable")]
+    begin: PartialDate,
+    #[serde(default, with = "nullable")]
+    end: PartialDate,
+    #[serde(default, with = "nullable")]
+    ended: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    ordering_key: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    area: Option<Area>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    artist: Option<Artist>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    event: Option<Event>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    genre: Option<Genre>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    instrument: Option<Instrument>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    label: Option<Label>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    place: Option<Place>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    recording: Option<Recording>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    release: Option<Release>,
+    #[serde(rename = "release_group", default, skip_serializing_if = "Option::is_none")]
+    release_group: Option<ReleaseGroup>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    series: Option<Series>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    url: Option<Url>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    work: Option<Work>
+}
+
+impl TryFrom<RelationshipJson> for Relationship {
+    type Error = String;
+
+    fn try_from(relation: RelationshipJson) -> Result<Relationship, String> {
+        let target = match relation.target_type.as_str() {
+            "area" => relation.area.map(Target::Area),
+            "artist" => relation.artist.map(Target::Artist),
+            "event" => relation.event.map(Target::Event),
+            "genre" => relation.genre.map(Target::Genre),
+            "instrument" => relation.instrument.map(Target::Instrument),
+            "label" => relation.label.map(Target::Label),
+            "place" => relation.place.map(Target::Place),
+            "recording" => relation.recording.map(Target::Recording),
+            "release" => relation.release.map(Target::Release),
+            "release_group" => relation.release_group.map(Target::ReleaseGroup),
+            "series" => relation.series.map(Target::Series),
+            "url" => relation.url.map(Target::Url),
+            "work" => relation.work.map(Target::Work),
+            x => return Err(format!("unknown target type '{}'", x))
+        };
+        let target = match target {
+            Some(x) => x,
+            None => return Err(format!("missing field '{}'", relation.target_type))
+        };
+
+        let type_id = match relation.type_id {
+            Some(ref x) => ::uuid::Uuid::parse_str(x).map_err(|e| format!("invalid MBID '{}': {:?}", x, e))?,
+            None => ::uuid::Uuid::nil()
+        };
+
+        Ok(Relationship {
+            relation_type: relation.relation_type,
+            type_id: type_id,
+            direction: relation.direction,
+            target: target,
+            attributes: relation.attributes,
+            attribute_values: relation.attribute_values,
+            begin: relation.begin,
+            end: relation.end,
+            ended: relation.ended,
+            ordering_key: relation.ordering_key
+        })
+    }
+}
+
+impl From<Relationship> for RelationshipJson {
+    fn from(relation: Relationship) -> RelationshipJson {
+        let mut json = RelationshipJson {
+            relation_type: relation.relation_type,
+            type_id: if relation.type_id.is_nil() { None } else { Some(relation.type_id.hyphenated().to_string()) },
+            direction: relation.direction,
+            target_type: relation.target.target_type().to_string(),
+            attributes: relation.attributes,
+            attribute_values: relation.attribute_values,
+            begin: relation.begin,
+            end: relation.end,
+            ended: relation.ended,
+            ordering_key: relation.ordering_key,
+            area: None,
+            artist: None,
+            event: None,
+            genre: None,
+            instrument: None,
+            label: None,
+            place: None,
+            recording: None,
+            release: None,
+            release_group: None,
+            series: None,
+            url: None,
+            work: None
+        };
+
+        match relation.target {
+            Target::Area(x) => json.area = Some(x),
+            Target::Artist(x) => json.artist = Some(x),
+            Target::Event(x) => json.event = Some(x),
+            Target::Genre(x) => json.genre = Some(x),
+            Target::Instrument(x) => json.instrument = Some(x),
+            Target::Label(x) => json.label = Some(x),
+            Target::Place(x) => json.place = Some(x),
+            Target::Recording(x) => json.recording = Some(x),
+            Target::Release(x) => json.release = Some(x),
+            Target::ReleaseGroup(x) => json.release_group = Some(x),
+            Target::Series(x) => json.series = Some(x),
+            Target::Url(x) => json.url = Some(x),
+            Target::Work(x) => json.work = Some(x)
+        }
+        json
+    }
+}
@@ -1,12 +1,20 @@
 use error::Error;
 use uuid::Uuid;
 use std::collections::HashMap;
+use browse::{LinkedEntity, Page};
+use paginated::Paginated;
+use search::SearchResult;
+use query::SearchQuery;
+use include::{self, Include, Request};
+#[cfg(feature = "async")]
+use async_client::{AsyncMusicBrainz, ApiFuture};
 
 pub trait Entity: Sized {
     /// Searches MusicBrainz for entities based on the search query.
     ///
-    /// Returns a `Vec` containing the entities matching the search query.
-    /// If no entities were found, returns an empty `Vec`.
+    /// Returns a `Page` containing the entities matching the search query, each
+    /// along with its score, and the total number of hits. Hits scoring below
+    /// the client's `min_score` are left out. If no entities were found, the page is empty.
     ///
     /// **NOTE**: `&self` is any `MusicBrainz` entity struct.
     ///
@@ -22,9 +30,32 @@ pub trait Entity: Sized {
     ///
     /// let search_results = musicbrainz.artist().search(&musicbrainz, &mut query).unwrap();
     ///
-    /// assert_eq!(search_results[0].id.hyphenated().to_string(), "4a00ec9d-c635-463a-8cd4-eb61725f0c60");
+    /// assert_eq!(search_results.items[0].entity.id.hyphenated().to_string(), "4a00ec9d-c635-463a-8cd4-eb61725f0c60");
     /// ```
-    fn search(&self, client: &super::MusicBrainz, params: &mut HashMap<&str, &str>) -> Result<Vec<Self>, Error>;
+    fn search(&self, client: &super::MusicBrainz, params: &mut HashMap<&str, &str>) -> Result<Page<SearchResult<Self>>, Error>;
+
+    /// Searches MusicBrainz for entities using a typed query builder.
+    ///
+    /// Same as `search`, but the Lucene query is built and escaped by one of the
+    /// builders in the `query` module instead of being written by hand.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use musicbrainz::*;
+    /// # use musicbrainz::query::ArtistQuery;
+    /// let musicbrainz = MusicBrainz::new();
+    /// let query = ArtistQuery::new().name("deadmau5").or().alias("deadmau5");
+    ///
+    /// let search_results = musicbrainz.artist().search_by(&musicbrainz, &query).unwrap();
+    /// ```
+    fn search_by<Q: SearchQuery<Entity=Self>>(&self, client: &super::MusicBrainz, query: &Q) -> Result<Page<SearchResult<Self>>, Error> {
+        let query = query.to_string();
+        let mut params = HashMap::new();
+        params.insert("query", query.as_str());
+
+        self.search(client, &mut params)
+    }
 
     /// Performs a lookup of an entity by using its MusicBrainz Identifier.
     ///
@@ -46,4 +77,133 @@ pub trait Entity: Sized {
     /// let result = musicbrainz.artist().lookup(&musicbrainz, &artist_id, &mut query);
     /// assert_eq!(result.unwrap(), compare)
     fn lookup(&self, client: &super::MusicBrainz, entity_id: &Uuid, params: &mut HashMap<&str, &str>) -> Result<Self, Error>;
+
+    /// Searches MusicBrainz for entities based on the search query, walking every page of results.
+    ///
+    /// Unlike `search`, pages are only requested as the returned iterator is consumed.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use musicbrainz::*;
+    /// # use std::collections::HashMap;
+    /// let musicbrainz = MusicBrainz::new();
+    /// let mut query = HashMap::new();
+    ///
+    /// query.insert("query", "deadmau5");
+    ///
+    /// for result in musicbrainz.artist().search_all(&musicbrainz, &query).take(50) {
+    ///     let result = result.unwrap();
+    ///     println!("{} {}", result.score, result.entity);
+    /// }
+    /// ```
+    fn search_all<'a>(&self, client: &'a super::MusicBrainz, params: &HashMap<&str, &str>) -> Paginated<'a, SearchResult<Self>>;
+
+    /// Returns the subqueries this entity supports for the given kind of request,
+    /// not counting relationship includes which are always supported.
+    fn includes(&self, request: Request) -> &'static [Include];
+
+    /// Performs a lookup of an entity by using its MusicBrainz Identifier, requesting
+    /// the given subqueries.
+    ///
+    /// Returns `Error::Unsupported` if one of the includes is not supported by this entity.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use musicbrainz::*;
+    /// # use musicbrainz::include::Include;
+    /// let musicbrainz = MusicBrainz::new();
+    ///
+    /// let artist_id = Uuid::parse_str("4a00ec9d-c635-463a-8cd4-eb61725f0c60").expect("failed to parse artist ID as Uuid");
+    /// let artist = musicbrainz.artist().lookup_with(&musicbrainz, &artist_id, &[Include::Tags, Include::ReleaseGroups]).unwrap();
+    /// ```
+    fn lookup_with(&self, client: &super::MusicBrainz, entity_id: &Uuid, includes: &[Include]) -> Result<Self, Error> {
+        let inc = include::to_param(Request::Lookup, self.includes(Request::Lookup), includes)?;
+        let mut params = HashMap::new();
+        if !inc.is_empty() {
+            params.insert("inc", inc.as_str());
+        }
+
+        self.lookup(client, entity_id, &mut params)
+    }
+}
+
+pub trait Browse: Entity {
+    /// Browses the entities linked to another entity, e.g. the release groups of an artist.
+    ///
+    /// Returns a single page of results; use the `limit` and `offset` parameters to
+    /// request the following ones. Returns `Error::Unsupported` if this entity cannot
+    /// be browsed by `linked`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use musicbrainz::*;
+    /// # use musicbrainz::browse::LinkedEntity;
+    /// # use std::collections::HashMap;
+    /// let musicbrainz = MusicBrainz::new();
+    /// let mut query = HashMap::new();
+    ///
+    /// query.insert("type", "album");
+    ///
+    /// let artist_id = Uuid::parse_str("4a00ec9d-c635-463a-8cd4-eb61725f0c60").expect("failed to parse artist ID as Uuid");
+    /// let page = musicbrainz.release_group().browse(&musicbrainz, &LinkedEntity::Artist(artist_id), &mut query).unwrap();
+    ///
+    /// assert!(page.count >= page.items.len());
+    /// ```
+    fn browse(&self, client: &super::MusicBrainz, linked: &LinkedEntity, params: &mut HashMap<&str, &str>) -> Result<Page<Self>, Error>;
+
+    /// Browses the entities linked to another entity, walking every page of results.
+    ///
+    /// See `Paginated` for an example.
+    fn browse_all<'a>(&self, client: &'a super::MusicBrainz, linked: &LinkedEntity, params: &HashMap<&str, &str>) -> Paginated<'a, Self>;
+
+    /// Browses the entities linked to another entity, requesting the given subqueries.
+    ///
+    /// Returns `Error::Unsupported` if one of the includes is not supported by this entity.
+    fn browse_with(&self, client: &super::MusicBrainz, linked: &LinkedEntity, includes: &[Include]) -> Result<Page<Self>, Error> {
+        let inc = include::to_param(Request::Browse, self.includes(Request::Browse), includes)?;
+        let mut params = HashMap::new();
+        if !inc.is_empty() {
+            params.insert("inc", inc.as_str());
+        }
+
+        self.browse(client, linked, &mut params)
+    }
+}
+
+/// The asynchronous counterpart of `Entity`, implemented by every entity.
+///
+/// Only available with the `async` feature. See the `async_client` module for an example.
+#[cfg(feature = "async")]
+pub trait AsyncEntity: Entity + Send + 'static {
+    /// Same as `Entity::search`, but returns a future instead of blocking.
+    fn search_async(&self, client: &AsyncMusicBrainz, params: &HashMap<&str, &str>) -> ApiFuture<Page<SearchResult<Self>>>;
+
+    /// Same as `Entity::search_by`, but returns a future instead of blocking.
+    fn search_by_async<Q: SearchQuery<Entity=Self>>(&self, client: &AsyncMusicBrainz, query: &Q) -> ApiFuture<Page<SearchResult<Self>>> {
+        let query = query.to_string();
+        let mut params = HashMap::new();
+        params.insert("query", query.as_str());
+
+        self.search_async(client, &params)
+    }
+
+    /// Same as `Entity::lookup`, but returns a future instead of blocking.
+    fn lookup_async(&self, client: &AsyncMusicBrainz, entity_id: &Uuid, params: &HashMap<&str, &str>) -> ApiFuture<Self>;
+
+    /// Same as `Entity::lookup_with`, but returns a future instead of blocking.
+    fn lookup_with_async(&self, client: &AsyncMusicBrainz, entity_id: &Uuid, includes: &[Include]) -> ApiFuture<Self> {
+        let inc = match include::to_param(Request::Lookup, self.includes(Request::Lookup), includes) {
+            Ok(x) => x,
+            Err(e) => return Box::pin(::std::future::ready(Err(e)))
+        };
+        let mut params = HashMap::new();
+        if !inc.is_empty() {
+            params.insert("inc", inc.as_str());
+        }
+
+        self.lookup_async(client, entity_id, &params)
+    }
 }
@@ -0,0 +1,108 @@
+use std::error;
+use std::fmt;
+use std::io::Read;
+use hyper;
+
+/// A `GET` request to the web service.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Request {
+    /// The full URL, including the query string.
+    pub url: String,
+    pub headers: Vec<(String, String)>
+}
+
+/// The response of the web service to a `Request`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Response {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: String
+}
+
+impl Response {
+    /// Returns the value of the first header called `name`, ignoring case.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.iter()
+            .find(|&&(ref header, _)| header.eq_ignore_ascii_case(name))
+            .map(|&(_, ref value)| value.as_str())
+    }
+}
+
+/// Sends requests to the web service on behalf of `MusicBrainz`.
+///
+/// The default implementation is `HyperTransport`; a custom transport can be
+/// used to plug in another HTTP stack, route requests through a proxy, or serve
+/// canned responses in tests.
+///
+/// # Example
+///
+/// ```no_run
+/// # use musicbrainz::*;
+/// # use musicbrainz::transport::{Transport, Request, Response};
+/// # use std::error::Error;
+/// struct NotFound;
+///
+/// impl Transport for NotFound {
+///     fn send(&self, _request: &Request) -> Result<Response, Box<dyn Error + Send + Sync>> {
+///         Ok(Response {
+///             status: 404,
+///             headers: Vec::new(),
+///             body: String::from(r#"{ "error": "Not Found" }"#)
+///         })
+///     }
+/// }
+///
+/// let musicbrainz = MusicBrainz::with_transport(Box::new(NotFound));
+/// ```
+pub trait Transport: Send + Sync {
+    /// Sends `request`, returning an error only if no response could be obtained.
+    ///
+    /// HTTP error statuses are not errors at this level and must be returned as a `Response`.
+    fn send(&self, request: &Request) -> Result<Response, Box<dyn error::Error + Send + Sync>>;
+}
+
+/// The default `Transport`, backed by a blocking `hyper::Client`.
+pub struct HyperTransport {
+    client: hyper::Client
+}
+
+impl HyperTransport {
+    pub fn new() -> HyperTransport {
+        HyperTransport::from_client(hyper::Client::new())
+    }
+
+    /// Uses an already configured client, e.g. one created with `hyper::Client::with_http_proxy`.
+    pub fn from_client(client: hyper::Client) -> HyperTransport {
+        HyperTransport {
+            client: client
+        }
+    }
+}
+
+impl fmt::Debug for HyperTransport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "HyperTransport")
+    }
+}
+
+impl Transport for HyperTransport {
+    fn send(&self, request: &Request) -> Result<Response, Box<dyn error::Error + Send + Sync>> {
+        let mut headers = hyper::header::Headers::new();
+        for &(ref name, ref value) in &request.headers {
+            headers.set_raw(name.clone(), vec![value.clone().into_bytes()]);
+        }
+
+        let mut res = self.client.get(&request.url)
+            .headers(headers)
+            .send()?;
+
+        let mut body = String::new();
+        res.read_to_string(&mut body)?;
+
+        Ok(Response {
+            status: res.status.to_u16(),
+            headers: res.headers.iter().map(|h| (h.name().to_string(), h.value_string())).collect(),
+            body: body
+        })
+    }
+}
@@ -0,0 +1,127 @@
+use uuid::Uuid;
+use std::collections::HashMap;
+use std::fmt;
+use traits::Entity;
+use error::Error;
+use parse;
+use include::{Include, Request};
+use paginated::Paginated;
+use browse::Page;
+use search::SearchResult;
+use json::JsonValue;
+use relationship::Relationship;
+#[cfg(feature = "async")]
+use traits::AsyncEntity;
+#[cfg(feature = "async")]
+use async_client::{AsyncMusicBrainz, ApiFuture};
+
+/// A URL known to MusicBrainz, such as an artist's homepage or a Discogs page.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub struct Url {
+    #[cfg_attr(feature = "serde", serde(with = "::serde_impls::uuid"))]
+    pub id: Uuid,
+    pub resource: String,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub relations: Vec<Relationship>
+}
+
+impl Url {
+    pub fn new(id: Uuid, resource: String) -> Url {
+        Url {
+            id: id,
+            resource: resource,
+            relations: Vec::new()
+        }
+    }
+
+    pub fn empty() -> Url {
+        Url::new(Uuid::nil(), String::new())
+    }
+
+    /// Performs a lookup of a URL entity by its address rather than by its MBID.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use musicbrainz::*;
+    /// # use std::collections::HashMap;
+    /// let musicbrainz = MusicBrainz::new();
+    /// let mut query = HashMap::new();
+    ///
+    /// let result = musicbrainz.url().lookup_resource(&musicbrainz, "http://www.deadmau5.com/", &mut query);
+    /// assert_eq!(result.unwrap().resource, "http://www.deadmau5.com/");
+    /// ```
+    pub fn lookup_resource<'a>(&self, client: &super::MusicBrainz, resource: &'a str, params: &mut HashMap<&str, &'a str>) -> Result<Url, Error> {
+        params.insert("resource", resource);
+
+        let url_data = match client.get("url", params) {
+            Ok(x) => x,
+            Err(e) => return Err(e)
+        };
+
+        Url::from_json(&url_data)
+    }
+
+    pub(crate) fn from_json(url: &JsonValue) -> Result<Url, Error> {
+        let id = parse::mbid(url, "id")?;
+
+        let mut result = Url::new(id, parse::string(url, "resource")?);
+        result.relations = Relationship::from_json(url)?;
+        Ok(result)
+    }
+}
+
+impl PartialEq for Url {
+    fn eq(&self, other: &Url) -> bool {
+        self.id == other.id && self.resource == other.resource
+    }
+}
+
+impl fmt::Display for Url {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{resource}", resource=self.resource)?;
+        writeln!(f, "Id: {id}", id=self.id)
+    }
+}
+
+/// Subqueries that can be included in a lookup request.
+const LOOKUP_INCLUDES: &'static [Include] = &[];
+
+impl Entity for Url {
+    fn search(&self, client: &super::MusicBrainz, params: &mut HashMap<&str, &str>) -> Result<Page<SearchResult<Self>>, Error> {
+        client.search("url", "urls", params, Url::from_json)
+    }
+
+    fn lookup(&self, client: &super::MusicBrainz, entity_id: &Uuid, params: &mut HashMap<&str, &str>) -> Result<Self, Error> {
+        let url_data = match client.get(&format!("url/{id}", id=entity_id), params) {
+            Ok(x) => x,
+            Err(e) => return Err(e)
+        };
+
+        Url::from_json(&url_data)
+    }
+
+    fn search_all<'a>(&self, client: &'a super::MusicBrainz, params: &HashMap<&str, &str>) -> Paginated<'a, SearchResult<Self>> {
+        client.search_all("url", "urls", params, Url::from_json)
+    }
+
+    fn includes(&self, request: Request) -> &'static [Include] {
+        match request {
+            Request::Lookup => LOOKUP_INCLUDES,
+            _ => &[]
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncEntity for Url {
+    fn search_async(&self, client: &AsyncMusicBrainz, params: &HashMap<&str, &str>) -> ApiFuture<Page<SearchResult<Self>>> {
+        client.search("url", "urls", params, Url::from_json)
+    }
+
+    fn lookup_async(&self, client: &AsyncMusicBrainz, entity_id: &Uuid, params: &HashMap<&str, &str>) -> ApiFuture<Self> {
+        client.lookup(&format!("url/{id}", id=entity_id), params, Url::from_json)
+    }
+}
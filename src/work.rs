@@ -0,0 +1,187 @@
+use uuid::Uuid;
+use std::collections::HashMap;
+use std::fmt;
+use traits::{Entity, Browse};
+use browse::{LinkedEntity, Page};
+use error::Error;
+use parse;
+use include::{Include, Request};
+use paginated::Paginated;
+use search::SearchResult;
+use json::JsonValue;
+use relationship::Relationship;
+#[cfg(feature = "async")]
+use traits::AsyncEntity;
+#[cfg(feature = "async")]
+use async_client::{AsyncMusicBrainz, ApiFuture};
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub struct Work {
+    #[cfg_attr(feature = "serde", serde(with = "::serde_impls::uuid"))]
+    pub id: Uuid,
+    pub title: String,
+    #[cfg_attr(feature = "serde", serde(rename = "type", default, with = "::serde_impls::nullable"))]
+    pub work_type: String,
+    #[cfg_attr(feature = "serde", serde(default, with = "::serde_impls::nullable"))]
+    pub disambiguation: String,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub iswcs: Vec<String>,
+    /// ISO 639-3 codes of the languages used in the lyrics of the work.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub languages: Vec<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub attributes: Vec<WorkAttribute>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub relations: Vec<Relationship>
+}
+
+/// An additional piece of information about a work, such as its key or an ID
+/// given to it by a rights society (e.g. `JASRAC ID`).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub struct WorkAttribute {
+    #[cfg_attr(feature = "serde", serde(rename = "type", default, with = "::serde_impls::nullable"))]
+    pub attribute_type: String,
+    #[cfg_attr(feature = "serde", serde(default, with = "::serde_impls::nullable"))]
+    pub value: String
+}
+
+impl Work {
+    pub fn new(id: Uuid, title: String, work_type: String, disambiguation: String, iswcs: Vec<String>, languages: Vec<String>, attributes: Vec<WorkAttribute>) -> Work {
+        Work {
+            id: id,
+            title: title,
+            work_type: work_type,
+            disambiguation: disambiguation,
+            iswcs: iswcs,
+            languages: languages,
+            attributes: attributes,
+            relations: Vec::new()
+        }
+    }
+
+    pub fn empty() -> Work {
+        Work::new(
+            Uuid::nil(),
+            String::new(),
+            String::new(),
+            String::new(),
+            Vec::new(),
+            Vec::new(),
+            Vec::new()
+        )
+    }
+
+    pub(crate) fn from_json(work: &JsonValue) -> Result<Work, Error> {
+        let id = parse::mbid(work, "id")?;
+
+        let iswcs = parse::strings(work, "iswcs")?;
+
+        let mut languages = parse::strings(work, "languages")?;
+
+        // Older servers only return a single `language` field.
+        if languages.is_empty() {
+            if let Some(x) = work["language"].as_str() {
+                languages.push(x.to_string());
+            }
+        }
+
+        let attributes = parse::list(work, "attributes", |attribute| {
+            Ok(WorkAttribute {
+                attribute_type: parse::string(attribute, "type")?,
+                value: parse::string(attribute, "value")?
+            })
+        })?;
+
+        let mut result = Work::new(
+            id,
+            parse::string(work, "title")?,
+            work["type"].as_str().unwrap_or("").to_string(),
+            work["disambiguation"].as_str().unwrap_or("").to_string(),
+            iswcs,
+            languages,
+            attributes
+        );
+        result.relations = Relationship::from_json(work)?;
+        Ok(result)
+    }
+}
+
+impl PartialEq for Work {
+    fn eq(&self, other: &Work) -> bool {
+        self.id == other.id && self.title == other.title
+    }
+}
+
+impl fmt::Display for Work {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{title}", title=self.title)?;
+        writeln!(f, "Id: {id}", id=self.id)
+    }
+}
+
+/// Subqueries that can be included in a lookup request.
+const LOOKUP_INCLUDES: &'static [Include] = &[
+    Include::Aliases, Include::Annotation, Include::Tags, Include::Genres, Include::UserTags,
+    Include::UserGenres, Include::Ratings, Include::UserRatings
+];
+
+/// Subqueries that can be included in a browse request.
+const BROWSE_INCLUDES: &'static [Include] = &[
+    Include::Aliases, Include::Annotation, Include::Tags, Include::Genres, Include::UserTags,
+    Include::UserGenres, Include::Ratings, Include::UserRatings
+];
+
+impl Entity for Work {
+    fn search(&self, client: &super::MusicBrainz, params: &mut HashMap<&str, &str>) -> Result<Page<SearchResult<Self>>, Error> {
+        client.search("work", "works", params, Work::from_json)
+    }
+
+    fn lookup(&self, client: &super::MusicBrainz, entity_id: &Uuid, params: &mut HashMap<&str, &str>) -> Result<Self, Error> {
+        let work_data = match client.get(&format!("work/{id}", id=entity_id), params) {
+            Ok(x) => x,
+            Err(e) => return Err(e)
+        };
+
+        Work::from_json(&work_data)
+    }
+
+    fn search_all<'a>(&self, client: &'a super::MusicBrainz, params: &HashMap<&str, &str>) -> Paginated<'a, SearchResult<Self>> {
+        client.search_all("work", "works", params, Work::from_json)
+    }
+
+    fn includes(&self, request: Request) -> &'static [Include] {
+        match request {
+            Request::Lookup => LOOKUP_INCLUDES,
+            Request::Browse => BROWSE_INCLUDES,
+            _ => &[]
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncEntity for Work {
+    fn search_async(&self, client: &AsyncMusicBrainz, params: &HashMap<&str, &str>) -> ApiFuture<Page<SearchResult<Self>>> {
+        client.search("work", "works", params, Work::from_json)
+    }
+
+    fn lookup_async(&self, client: &AsyncMusicBrainz, entity_id: &Uuid, params: &HashMap<&str, &str>) -> ApiFuture<Self> {
+        client.lookup(&format!("work/{id}", id=entity_id), params, Work::from_json)
+    }
+}
+
+/// Entities that can be linked to in a browse request.
+const BROWSE_BY: &'static [&'static str] = &["artist", "collection"];
+
+impl Browse for Work {
+    fn browse(&self, client: &super::MusicBrainz, linked: &LinkedEntity, params: &mut HashMap<&str, &str>) -> Result<Page<Self>, Error> {
+        client.browse("work", "works", BROWSE_BY, linked, params, Work::from_json)
+    }
+
+    fn browse_all<'a>(&self, client: &'a super::MusicBrainz, linked: &LinkedEntity, params: &HashMap<&str, &str>) -> Paginated<'a, Self> {
+        client.browse_all("work", "works", BROWSE_BY, linked, params, Work::from_json)
+    }
+}
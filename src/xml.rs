@@ -0,0 +1,187 @@
+//! Reads responses in the MusicBrainz XML Metadata format (MMD).
+//!
+//! Rather than duplicating every parser, the XML is rewritten into the structure the
+//! web service returns with `fmt=json`, which the entities are then read from:
+//!
+//! - attributes and leaf elements become fields, e.g. `<artist id="...">` and
+//!   `<name>deadmau5</name>`; the `id` of a leaf is kept as `<name>-id`, as in
+//!   `<gender id="...">Male</gender>`;
+//! - `<xxx-list>` elements become arrays named after the plural of `xxx`, along with
+//!   `xxx-count` and `xxx-offset`;
+//! - the `artist-credit` element becomes the array of its name credits;
+//! - the `relation-list` of every target type is merged into a single `relations`
+//!   array, each relation carrying its `target-type`, and the values of relationship
+//!   attributes are gathered in `attribute-values`.
+//!
+//! Only compiled with the `xml` feature.
+
+use json::JsonValue;
+use roxmltree::{Document, Node};
+use error::Error;
+
+/// Rewrites an MMD document into its JSON counterpart.
+///
+/// An `<error>` document is read as the `{ "error": ..., "help": ... }` object
+/// returned alongside unsuccessful statuses.
+pub fn to_json(body: &str) -> Result<JsonValue, Error> {
+    let document = match Document::parse(body) {
+        Ok(x) => x,
+        Err(e) => return Err(Error::ParseXml(e))
+    };
+
+    let root = document.root_element();
+    match root.tag_name().name() {
+        "metadata" => {
+            let mut data = JsonValue::new_object();
+            match elements(root).next() {
+                Some(node) if is_list(node) => insert_list(&mut data, node, true),
+                Some(node) => data = object(node),
+                None => {}
+            }
+            Ok(data)
+        },
+        "error" => {
+            let mut texts = elements(root).filter(|x| x.tag_name().name() == "text");
+            let mut data = JsonValue::new_object();
+            data["error"] = texts.next().and_then(|x| x.text()).unwrap_or("").into();
+            if let Some(help) = texts.next().and_then(|x| x.text()) {
+                data["help"] = help.into();
+            }
+            Ok(data)
+        },
+        name => Err(Error::Unsupported(format!("unexpected root element '{}'", name)))
+    }
+}
+
+fn elements<'a, 'input>(node: Node<'a, 'input>) -> impl Iterator<Item = Node<'a, 'input>> {
+    node.children().filter(|x| x.is_element())
+}
+
+fn is_list(node: Node) -> bool {
+    node.tag_name().name().ends_with("-list")
+}
+
+fn is_leaf(node: Node) -> bool {
+    elements(node).next().is_none()
+}
+
+/// Reads an element holding other elements.
+fn object(node: Node) -> JsonValue {
+    let mut data = JsonValue::new_object();
+    for attribute in node.attributes() {
+        data[attribute.name()] = scalar(attribute.name(), attribute.value());
+    }
+
+    for child in elements(node) {
+        let name = child.tag_name().name();
+        if is_list(child) {
+            insert_list(&mut data, child, false);
+        } else if name == "artist-credit" {
+            data[name] = JsonValue::Array(elements(child).map(object).collect());
+        } else if is_leaf(child) {
+            data[name] = scalar(name, child.text().unwrap_or(""));
+            if let Some(id) = child.attribute("id") {
+                data[format!("{}-id", name).as_str()] = id.into();
+            }
+        } else {
+            data[name] = object(child);
+        }
+    }
+
+    data
+}
+
+/// Inserts the items of a `<xxx-list>` element into `data`, along with their count
+/// and offset. At the root of a search or browse response, these are also stored as
+/// `count` and `offset`.
+fn insert_list(data: &mut JsonValue, list: Node, root: bool) {
+    let item = list.tag_name().name().trim_end_matches("-list");
+    let key = plural(item);
+    let key = key.as_str();
+
+    if !data[key].is_array() {
+        data[key] = JsonValue::new_array();
+    }
+    for child in elements(list) {
+        // Relationship attributes holding a value, e.g. `<attribute value="1">number</attribute>`.
+        if item == "attribute" {
+            if let Some(value) = child.attribute("value") {
+                if !data["attribute-values"].is_object() {
+                    data["attribute-values"] = JsonValue::new_object();
+                }
+                data["attribute-values"][child.text().unwrap_or("")] = value.into();
+            }
+        }
+
+        let mut value = list_item(child);
+        if let Some(target_type) = list.attribute("target-type") {
+            value["target-type"] = target_type.into();
+            // URLs are only given as `<target id="...">resource</target>`.
+            if target_type == "url" && value["url"].is_null() {
+                let mut url = JsonValue::new_object();
+                url["id"] = value["target-id"].clone();
+                url["resource"] = value["target"].clone();
+                value["url"] = url;
+            }
+        }
+        // Pushing onto an array cannot fail.
+        let _ = data[key].push(value);
+    }
+
+    for field in &["count", "offset"] {
+        if let Some(value) = list.attribute(*field) {
+            data[format!("{}-{}", item, field).as_str()] = scalar(field, value);
+            if root {
+                data[*field] = scalar(field, value);
+            }
+        }
+    }
+}
+
+/// Reads an item of a list. Leaf items are read as strings, except for aliases and
+/// typed attributes (e.g. the key of a work), which keep their attributes.
+fn list_item(node: Node) -> JsonValue {
+    if !is_leaf(node) {
+        return object(node);
+    }
+
+    let text = node.text().unwrap_or("");
+    let name = node.tag_name().name();
+    if name == "alias" || (name == "attribute" && node.attribute("type").is_some()) {
+        let mut data = JsonValue::new_object();
+        for attribute in node.attributes() {
+            data[attribute.name()] = attribute.value().into();
+        }
+        data[if name == "alias" { "name" } else { "value" }] = text.into();
+        return data;
+    }
+
+    if text.is_empty() {
+        // ISRCs are given as `<isrc id="..."/>`.
+        node.attribute("id").unwrap_or("").into()
+    } else {
+        text.into()
+    }
+}
+
+/// The name of the JSON array holding the items of `<item-list>`.
+fn plural(item: &str) -> String {
+    match item {
+        "medium" => "media".to_string(),
+        "alias" => "aliases".to_string(),
+        "series" | "label-info" => item.to_string(),
+        _ => format!("{}s", item)
+    }
+}
+
+/// Reads the value of a leaf, using the type it has in the JSON format.
+fn scalar(name: &str, value: &str) -> JsonValue {
+    match name {
+        "ended" | "video" | "cancelled" => JsonValue::Boolean(value == "true"),
+        "length" | "position" | "score" | "label-code" | "count" | "offset" | "track-count" | "ordering-key" => match value.parse::<u64>() {
+            Ok(x) => x.into(),
+            Err(_) => value.into()
+        },
+        _ => value.into()
+    }
+}
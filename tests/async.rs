@@ -0,0 +1,124 @@
+extern crate musicbrainz;
+
+use musicbrainz::*;
+use musicbrainz::async_client::{AsyncMusicBrainz, AsyncTransport, TransportFuture};
+use musicbrainz::rate_limit::RateLimiter;
+use musicbrainz::transport::{Request, Response};
+use std::collections::HashMap;
+use std::future::{self, Future};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, Thread};
+use std::time::{Duration, Instant};
+
+/// Runs `future` to completion on the current thread.
+fn block_on<F: Future>(future: F) -> F::Output {
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut context = Context::from_waker(&waker);
+    let mut future = Box::pin(future);
+    loop {
+        match future.as_mut().poll(&mut context) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park()
+        }
+    }
+}
+
+struct FakeTransport {
+    requests: AtomicUsize
+}
+
+impl AsyncTransport for FakeTransport {
+    fn send(&self, request: &Request) -> TransportFuture {
+        self.requests.fetch_add(1, Ordering::SeqCst);
+
+        let body = if request.url.contains("/artist/?") || request.url.contains("/artist?") {
+            r#"{ "count": 1, "offset": 0, "artists": [{ "id": "4a00ec9d-c635-463a-8cd4-eb61725f0c60", "name": "deadmau5", "score": 100 }] }"#
+        } else {
+            r#"{ "id": "4a00ec9d-c635-463a-8cd4-eb61725f0c60", "name": "deadmau5", "type": "Person" }"#
+        };
+
+        Box::pin(future::ready(Ok(Response {
+            status: 200,
+            headers: Vec::new(),
+            body: String::from(body)
+        })))
+    }
+}
+
+#[test]
+fn test_async_lookup_and_search() {
+    let musicbrainz = AsyncMusicBrainz::with_transport(Box::new(FakeTransport { requests: AtomicUsize::new(0) }));
+    let artist_id = Uuid::parse_str("4a00ec9d-c635-463a-8cd4-eb61725f0c60").unwrap();
+
+    let artist = block_on(musicbrainz.artist().lookup_async(&musicbrainz, &artist_id, &HashMap::new())).unwrap();
+    assert_eq!(artist.name, "deadmau5");
+
+    let mut query = HashMap::new();
+    query.insert("query", "deadmau5");
+    let page = block_on(musicbrainz.artist().search_async(&musicbrainz, &query)).unwrap();
+    assert_eq!(page.items[0].entity.id, artist_id);
+}
+
+#[test]
+fn test_async_rate_limit() {
+    let mut musicbrainz = AsyncMusicBrainz::with_transport(Box::new(FakeTransport { requests: AtomicUsize::new(0) }));
+    musicbrainz.set_rate_limit(Some(RateLimiter::new(1, Duration::from_millis(100))));
+    let artist_id = Uuid::parse_str("4a00ec9d-c635-463a-8cd4-eb61725f0c60").unwrap();
+
+    let start = Instant::now();
+    let lookups: Vec<_> = (0..3)
+        .map(|_| musicbrainz.artist().lookup_async(&musicbrainz, &artist_id, &HashMap::new()))
+        .collect();
+    for lookup in lookups {
+        assert!(block_on(lookup).is_ok());
+    }
+
+    assert!(start.elapsed() >= Duration::from_millis(190));
+}
+
+#[test]
+fn test_blocking_transport_reuses_its_worker() {
+    use musicbrainz::async_client::BlockingTransport;
+    use musicbrainz::transport::Transport;
+    use std::sync::Mutex;
+
+    /// Records the thread every request is sent from.
+    struct Threads(Arc<Mutex<Vec<thread::ThreadId>>>);
+
+    impl Transport for Threads {
+        fn send(&self, _request: &Request) -> Result<Response, Box<dyn std::error::Error + Send + Sync>> {
+            self.0.lock().unwrap().push(thread::current().id());
+            Ok(Response {
+                status: 200,
+                headers: Vec::new(),
+                body: String::from(r#"{ "id": "4a00ec9d-c635-463a-8cd4-eb61725f0c60", "name": "deadmau5", "type": "Person" }"#)
+            })
+        }
+    }
+
+    let threads = Arc::new(Mutex::new(Vec::new()));
+    let mut musicbrainz = AsyncMusicBrainz::with_transport(Box::new(BlockingTransport::new(Box::new(Threads(threads.clone())))));
+    musicbrainz.set_rate_limit(Some(RateLimiter::new(2, Duration::from_millis(50))));
+    let artist_id = Uuid::parse_str("4a00ec9d-c635-463a-8cd4-eb61725f0c60").unwrap();
+
+    let lookups: Vec<_> = (0..4)
+        .map(|_| musicbrainz.artist().lookup_async(&musicbrainz, &artist_id, &HashMap::new()))
+        .collect();
+    for lookup in lookups {
+        assert_eq!(block_on(lookup).unwrap().name, "deadmau5");
+    }
+
+    let threads = threads.lock().unwrap();
+    assert_eq!(threads.len(), 4);
+    assert!(threads.iter().all(|x| *x == threads[0] && *x != thread::current().id()));
+}
@@ -0,0 +1,163 @@
+//! Tests against responses recorded in `tests/fixtures`, run with `cargo test --features testing`.
+//!
+//! Every test runs against each response format; with the `xml` feature, the
+//! entities read from the XML fixtures must also match those read from JSON.
+
+extern crate musicbrainz;
+
+use musicbrainz::*;
+use musicbrainz::fixture::FixtureTransport;
+use musicbrainz::format::Format;
+use musicbrainz::query::ReleaseGroupQuery;
+use std::collections::HashMap;
+
+fn client(format: Format) -> MusicBrainz {
+    let mut musicbrainz = MusicBrainz::with_transport(Box::new(FixtureTransport::replay(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures"))));
+    musicbrainz.set_rate_limit(None);
+    musicbrainz.set_format(format);
+    musicbrainz
+}
+
+#[cfg(not(feature = "xml"))]
+fn formats() -> Vec<Format> {
+    vec![Format::Json]
+}
+
+#[cfg(feature = "xml")]
+fn formats() -> Vec<Format> {
+    vec![Format::Json, Format::Xml]
+}
+
+fn lookup_artist(format: Format) -> artist::Artist {
+    let musicbrainz = client(format);
+    let mut query = HashMap::new();
+    query.insert("inc", "release-groups+tags");
+
+    let artist_id = Uuid::parse_str("4a00ec9d-c635-463a-8cd4-eb61725f0c60").unwrap();
+    musicbrainz.artist().lookup(&musicbrainz, &artist_id, &mut query).unwrap()
+}
+
+fn search_release_groups(format: Format) -> browse::Page<search::SearchResult<release_group::ReleaseGroup>> {
+    let musicbrainz = client(format);
+    let query = ReleaseGroupQuery::new().title("For Lack of a Better Name").artist("deadmau5");
+
+    musicbrainz.release_group().search_by(&musicbrainz, &query).unwrap()
+}
+
+#[test]
+fn test_artist_lookup() {
+    for format in formats() {
+        let artist = lookup_artist(format);
+
+        assert_eq!(artist.name, "deadmau5");
+        assert_eq!(artist.artist_type, enums::PersonType::Person);
+        assert_eq!(artist.tags, vec!["electro house", "progressive house"]);
+        assert_eq!(artist.release_groups.len(), 2);
+        assert_eq!(artist.release_groups[0].title, "For Lack of a Better Name");
+        assert_eq!(artist.release_groups[0].release_date.to_string(), "2009-09-22");
+    }
+}
+
+#[test]
+fn test_release_group_search() {
+    for format in formats() {
+        let results = search_release_groups(format);
+
+        assert_eq!(results.count, 2);
+        assert_eq!(results.items.len(), 2);
+        assert_eq!(results.items[0].score, 100);
+        assert_eq!(results.items[0].entity.title, "For Lack of a Better Name");
+        assert_eq!(results.items[1].score, 62);
+    }
+}
+
+#[cfg(feature = "xml")]
+#[test]
+fn test_formats_yield_identical_entities() {
+    // `PartialEq` only compares identifiers, so compare every field through `Debug`.
+    assert_eq!(format!("{:?}", lookup_artist(Format::Json)), format!("{:?}", lookup_artist(Format::Xml)));
+    assert_eq!(format!("{:?}", search_release_groups(Format::Json)), format!("{:?}", search_release_groups(Format::Xml)));
+}
+
+#[test]
+fn test_relationships() {
+    use musicbrainz::enums::Direction;
+    use musicbrainz::include::Include;
+    use musicbrainz::relationship::Target;
+
+    for format in formats() {
+        let musicbrainz = client(format);
+        let artist_id = Uuid::parse_str("4a00ec9d-c635-463a-8cd4-eb61725f0c60").unwrap();
+        let artist = musicbrainz.artist().lookup_with(&musicbrainz, &artist_id, &[Include::ArtistRels, Include::SeriesRels, Include::UrlRels]).unwrap();
+
+        assert_eq!(artist.relations.len(), 3);
+
+        let band = &artist.relations[0];
+        assert_eq!(band.relation_type, "member of band");
+        assert_eq!(band.direction, Direction::Forward);
+        assert_eq!(band.attributes, vec!["original"]);
+        assert_eq!(band.begin.year, Some(2009));
+        assert_eq!(band.end.to_string(), "2011");
+        assert!(band.ended);
+        match band.target {
+            Target::Artist(ref x) => assert_eq!(x.name, "BSOD"),
+            ref other => panic!("unexpected target: {:?}", other)
+        }
+
+        let series = &artist.relations[1];
+        assert_eq!(series.target.target_type(), "series");
+        assert_eq!(series.attribute_values.get("number").map(|x| x.as_str()), Some("3"));
+        assert_eq!(series.ordering_key, Some(3));
+
+        match artist.relations[2].target {
+            Target::Url(ref x) => assert_eq!(x.resource, "http://www.deadmau5.com/"),
+            ref other => panic!("unexpected target: {:?}", other)
+        }
+    }
+}
+
+#[test]
+fn test_recording_a_retry_replaces_the_failed_status() {
+    use musicbrainz::transport::{Transport, Request, Response};
+    use std::error::Error;
+    use std::sync::Mutex;
+
+    /// Answers with the given responses, in order.
+    struct Responses(Mutex<Vec<Response>>);
+
+    impl Transport for Responses {
+        fn send(&self, _request: &Request) -> Result<Response, Box<dyn Error + Send + Sync>> {
+            Ok(self.0.lock().unwrap().remove(0))
+        }
+    }
+
+    let response = |status: u16, body: &str| Response { status: status, headers: Vec::new(), body: body.to_string() };
+    let dir = std::env::temp_dir().join(format!("musicbrainz-fixtures-{}", std::process::id()));
+    let request = Request {
+        url: "https://musicbrainz.org/ws/2/artist?fmt=json&query=artist%3Adeadmau5".to_string(),
+        headers: Vec::new()
+    };
+
+    let recorder = FixtureTransport::record(&dir, Box::new(Responses(Mutex::new(vec![
+        response(503, r#"{ "error": "Service Unavailable" }"#),
+        response(200, r#"{ "artists": [] }"#)
+    ]))));
+    assert_eq!(recorder.send(&request).unwrap().status, 503);
+    assert_eq!(recorder.send(&request).unwrap().status, 200);
+
+    let replayed = FixtureTransport::replay(&dir).send(&request).unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+    assert_eq!(replayed.status, 200);
+    assert_eq!(replayed.body, r#"{ "artists": [] }"#);
+}
+
+#[test]
+fn test_distinct_queries_use_distinct_fixtures() {
+    use musicbrainz::transport::Request;
+
+    let transport = FixtureTransport::replay("tests/fixtures");
+    let path = |url: &str| transport.fixture_path(&Request { url: url.to_string(), headers: Vec::new() }).unwrap();
+
+    assert_ne!(path("https://musicbrainz.org/ws/2/artist?query=a%3Ab"), path("https://musicbrainz.org/ws/2/artist?query=a%2Fb"));
+    assert_ne!(path("https://musicbrainz.org/ws/2/artist?query=a+b"), path("https://musicbrainz.org/ws/2/artist?query=a_b"));
+}
@@ -1,5 +1,16 @@
 extern crate musicbrainz;
+extern crate json;
 use musicbrainz::*;
+use std::collections::HashMap;
+
+/// A client replaying the responses recorded in `tests/fixtures`.
+#[cfg(feature = "testing")]
+fn fixtures() -> MusicBrainz {
+    let transport = fixture::FixtureTransport::replay(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures"));
+    let mut musicbrainz = MusicBrainz::with_transport(Box::new(transport));
+    musicbrainz.set_rate_limit(None);
+    musicbrainz
+}
 
 #[test]
 fn test_artist_equal() {
@@ -46,4 +57,718 @@ fn test_artist_not_equal() {
     );
 
     assert_eq!(a, b);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_release_equal() {
+    let mut a = release::Release::empty();
+    a.title = String::from("insert title here");
+
+    let mut b = release::Release::empty();
+    b.title = String::from("insert title here");
+    b.barcode = String::from("724384960650");
+    b.status = Some(enums::ReleaseStatus::Official);
+
+    assert_eq!(a, b);
+}
+
+
+#[test]
+fn test_label_type_round_trip() {
+    let label_type = "Original Production".parse::<enums::LabelType>().unwrap();
+
+    assert_eq!(label_type, enums::LabelType::OriginalProduction);
+    assert_eq!(label_type.to_string(), "Original Production");
+}
+
+
+#[test]
+fn test_documented_types_round_trip() {
+    // https://musicbrainz.org/doc/Release_Group/Type and https://musicbrainz.org/doc/Release#Status
+    let album_types = [
+        "Album", "Single", "EP", "Broadcast", "Other", "Compilation", "Soundtrack", "Spokenword",
+        "Interview", "Audiobook", "Audio drama", "Live", "Remix", "DJ-mix", "Mixtape/Street", "Demo",
+        "Field recording"
+    ];
+    for value in album_types.iter() {
+        assert_eq!(value.parse::<enums::AlbumType>().map(|x| x.to_string()), Ok(value.to_string()));
+    }
+
+    let statuses = ["Official", "Promotion", "Bootleg", "Pseudo-Release", "Withdrawn", "Expunged", "Cancelled"];
+    for value in statuses.iter() {
+        assert_eq!(value.parse::<enums::ReleaseStatus>().map(|x| x.to_string()), Ok(value.to_string()));
+    }
+}
+
+
+#[test]
+fn test_linked_entity_display() {
+    let artist_id = Uuid::parse_str("4a00ec9d-c635-463a-8cd4-eb61725f0c60").unwrap();
+    let linked = browse::LinkedEntity::ReleaseGroup(artist_id);
+
+    assert_eq!(linked.to_string(), "release-group=4a00ec9d-c635-463a-8cd4-eb61725f0c60");
+}
+
+
+#[test]
+fn test_unsupported_search_all() {
+    let musicbrainz = MusicBrainz::new();
+    let mut results = musicbrainz.genre().search_all(&musicbrainz, &HashMap::new());
+
+    assert!(results.next().unwrap().is_err());
+    assert!(results.next().is_none());
+}
+
+
+#[cfg(feature = "testing")]
+#[test]
+fn test_url_search() {
+    let musicbrainz = fixtures();
+    let query = query::UrlQuery::new().resource("http://www.deadmau5.com/");
+    let results = musicbrainz.url().search_by(&musicbrainz, &query).unwrap();
+
+    assert_eq!(results.count, 1);
+    assert_eq!(results.items[0].score, 100);
+    assert_eq!(results.items[0].entity.id.hyphenated().to_string(), "6e3cbf7a-2f14-4c8e-b1d9-8a0f5c3e2d61");
+    assert_eq!(results.items[0].entity.resource, "http://www.deadmau5.com/");
+}
+
+
+#[cfg(feature = "testing")]
+#[test]
+fn test_recording_lookup() {
+    use include::Include;
+
+    let musicbrainz = fixtures();
+    let recording_id = Uuid::parse_str("62c2e20a-559e-422f-a44c-9afa7882f0c4").unwrap();
+    let recording = musicbrainz.recording().lookup_with(&musicbrainz, &recording_id, &[Include::Isrcs, Include::ArtistCredits]).unwrap();
+
+    assert_eq!(recording.title, "FML");
+    assert_eq!(recording.length, Some(463000));
+    assert!(!recording.video);
+    assert_eq!(recording.disambiguation, "album version");
+    assert_eq!(recording.isrcs, vec!["CAN110900212", "GBCEN0900123"]);
+    assert_eq!(recording.artist_credit.to_string(), "deadmau5");
+    assert_eq!(recording.artist_credit.artists()[0].id.hyphenated().to_string(), "4a00ec9d-c635-463a-8cd4-eb61725f0c60");
+}
+
+
+#[cfg(feature = "testing")]
+#[test]
+fn test_work_lookup() {
+    let musicbrainz = fixtures();
+    let work_id = Uuid::parse_str("f6b3c3a0-2c0e-3c4b-9c8e-6b0a1b2c3d4e").unwrap();
+    let work = musicbrainz.work().lookup(&musicbrainz, &work_id, &mut HashMap::new()).unwrap();
+
+    assert_eq!(work.title, "Ghosts 'n' Stuff");
+    assert_eq!(work.work_type, "Song");
+    assert_eq!(work.iswcs, vec!["T-912.345.678-9"]);
+    assert_eq!(work.languages, vec!["eng", "zxx"]);
+    assert_eq!(work.attributes.len(), 1);
+    assert_eq!((work.attributes[0].attribute_type.as_str(), work.attributes[0].value.as_str()), ("Key", "F minor"));
+
+    // Older servers only return a single language.
+    let musicbrainz = MusicBrainz::with_transport(Box::new(Body(
+        r#"{ "id": "f6b3c3a0-2c0e-3c4b-9c8e-6b0a1b2c3d4e", "title": "Ghosts 'n' Stuff", "language": "eng" }"#
+    )));
+    let work = musicbrainz.work().lookup(&musicbrainz, &work_id, &mut HashMap::new()).unwrap();
+    assert_eq!(work.languages, vec!["eng"]);
+}
+
+
+#[cfg(feature = "testing")]
+#[test]
+fn test_area_place_and_event_lookups() {
+    use partial_date::PartialDate;
+
+    let musicbrainz = fixtures();
+
+    let area_id = Uuid::parse_str("71bbafaa-e825-3e15-8ca9-017dcad1748b").unwrap();
+    let area = musicbrainz.area().lookup(&musicbrainz, &area_id, &mut HashMap::new()).unwrap();
+    assert_eq!((area.name.as_str(), area.area_type.as_str()), ("Canada", "Country"));
+    assert_eq!(area.iso_3166_1_codes, vec!["CA"]);
+    assert!(area.iso_3166_2_codes.is_empty());
+    assert_eq!(area.life_span.begin.to_string(), "1867-07-01");
+    assert!(!area.life_span.ended);
+
+    let place_id = Uuid::parse_str("4352063b-a833-421b-a420-e7fb295dece0").unwrap();
+    let place = musicbrainz.place().lookup(&musicbrainz, &place_id, &mut HashMap::new()).unwrap();
+    assert_eq!((place.name.as_str(), place.place_type.as_str()), ("Royal Albert Hall", "Venue"));
+    assert_eq!(place.address, "Kensington Gore, London SW7 2AP");
+    assert_eq!(place.area, "London");
+    // The latitude is given as a string and the longitude as a number.
+    let coordinates = place.coordinates.unwrap();
+    assert_eq!((coordinates.latitude, coordinates.longitude), (51.50105, -0.17748));
+
+    let event_id = Uuid::parse_str("6f2b7a1c-8d3e-4f5a-9b0c-1d2e3f4a5b6c").unwrap();
+    let event = musicbrainz.event().lookup(&musicbrainz, &event_id, &mut HashMap::new()).unwrap();
+    assert_eq!((event.name.as_str(), event.event_type.as_str()), ("deadmau5 at Royal Albert Hall", "Concert"));
+    assert_eq!(event.time, "19:30");
+    assert!(!event.cancelled);
+    assert!(event.setlist.starts_with("* FML"));
+    assert_eq!(event.life_span.begin, PartialDate::new(Some(2010), None, Some(26)));
+    assert!(event.life_span.ended);
+}
+
+
+#[cfg(feature = "testing")]
+#[test]
+fn test_release_tracklist() {
+    use include::Include;
+
+    let musicbrainz = fixtures();
+    let release_id = Uuid::parse_str("b9ba9c3e-0a0b-4d8f-8f5e-9a6d9c8e7f10").unwrap();
+    let release = musicbrainz.release().lookup_with(&musicbrainz, &release_id, &[Include::Media, Include::Recordings, Include::DiscIds]).unwrap();
+
+    assert_eq!(release.media.len(), 2);
+
+    let cd = &release.media[0];
+    assert_eq!((cd.position, cd.format.as_str(), cd.track_count), (1, "CD", 2));
+    assert_eq!(cd.disc_ids, vec!["Q6mF0o6aoZ0XHuVkq2BbQIPS8Vk-"]);
+    assert_eq!(cd.tracks.len(), 2);
+    assert_eq!((cd.tracks[1].position, cd.tracks[1].number.as_str()), (2, "2"));
+    assert_eq!(cd.tracks[1].title, "Moar Ghosts 'n' Stuff");
+    assert_eq!(cd.tracks[1].length, Some(353000));
+    assert_eq!(cd.tracks[1].recording.hyphenated().to_string(), "5e2a0f1d-8f7c-4a6b-9d3e-1c2b3a4f5e60");
+
+    let vinyl = &release.media[1];
+    assert_eq!(vinyl.title, "Bonus");
+    assert!(vinyl.disc_ids.is_empty());
+    assert_eq!(vinyl.tracks[0].number, "A1");
+    assert_eq!(vinyl.tracks[0].length, None);
+}
+
+
+#[cfg(feature = "testing")]
+#[test]
+fn test_instrument_lookup() {
+    let musicbrainz = fixtures();
+    let instrument_id = Uuid::parse_str("b3eac5f9-7859-4416-ac39-7154e2e8d348").unwrap();
+    let instrument = musicbrainz.instrument().lookup(&musicbrainz, &instrument_id, &mut HashMap::new()).unwrap();
+
+    assert_eq!(instrument.name, "piano");
+    assert_eq!(instrument.instrument_type, "String instrument");
+    assert!(instrument.description.starts_with("The piano is an acoustic"));
+    assert_eq!(instrument.disambiguation, "");
+}
+
+
+#[cfg(feature = "testing")]
+#[test]
+fn test_series_lookup() {
+    let musicbrainz = fixtures();
+    let series_id = Uuid::parse_str("a7e1c8f0-5d1b-4e0c-9a3f-2b6d8e4c1f07").unwrap();
+    let series = musicbrainz.series().lookup(&musicbrainz, &series_id, &mut HashMap::new()).unwrap();
+
+    assert_eq!(series.name, "Mau5trap Artists");
+    assert_eq!(series.series_type, "Artist series");
+    assert_eq!(series.disambiguation, "label roster");
+}
+
+
+#[cfg(feature = "testing")]
+#[test]
+fn test_url_lookup() {
+    use include::Include;
+    use relationship::Target;
+
+    let musicbrainz = fixtures();
+    let url_id = Uuid::parse_str("6e3cbf7a-2f14-4c8e-b1d9-8a0f5c3e2d61").unwrap();
+    let url = musicbrainz.url().lookup_with(&musicbrainz, &url_id, &[Include::ArtistRels]).unwrap();
+
+    assert_eq!(url.resource, "http://www.deadmau5.com/");
+    assert_eq!(url.relations.len(), 1);
+    assert_eq!(url.relations[0].direction, enums::Direction::Backward);
+    match url.relations[0].target {
+        Target::Artist(ref x) => assert_eq!(x.name, "deadmau5"),
+        ref other => panic!("unexpected target: {:?}", other)
+    }
+}
+
+
+#[test]
+fn test_genre_all() {
+    /// Serves `genre/all` in pages of at most two genres.
+    struct Genres(bool);
+
+    impl transport::Transport for Genres {
+        fn send(&self, request: &transport::Request) -> Result<transport::Response, Box<dyn std::error::Error + Send + Sync>> {
+            assert!(request.url.starts_with("https://musicbrainz.org/ws/2/genre/all"));
+            let body = if !self.0 {
+                r#"{ "genre-count": 3, "genre-offset": 0 }"#
+            } else if request.url.contains("offset=0") {
+                r#"{ "genre-count": 3, "genre-offset": 0, "genres": [
+                       { "id": "89255676-1f14-4dd8-bbad-fca839d6aff4", "name": "electronic" },
+                       { "id": "aac07ae0-8acf-4249-b5c0-2762b53947a2", "name": "house" } ] }"#
+            } else {
+                assert!(request.url.contains("offset=2"));
+                r#"{ "genre-count": 3, "genre-offset": 2, "genres": [
+                       { "id": "ea9a2a8e-4b2c-4c4d-9a4e-6f3c6b7d1e21", "name": "progressive house" } ] }"#
+            };
+            Ok(transport::Response { status: 200, headers: Vec::new(), body: String::from(body) })
+        }
+    }
+
+    let mut musicbrainz = MusicBrainz::with_transport(Box::new(Genres(true)));
+    musicbrainz.set_rate_limit(None);
+    let genres = genre::Genre::all(&musicbrainz).unwrap();
+    let names: Vec<&str> = genres.iter().map(|x| x.name.as_str()).collect();
+    assert_eq!(names, vec!["electronic", "house", "progressive house"]);
+
+    let mut musicbrainz = MusicBrainz::with_transport(Box::new(Genres(false)));
+    musicbrainz.set_rate_limit(None);
+    match genre::Genre::all(&musicbrainz) {
+        Err(error::Error::MissingField(field)) => assert_eq!(field, "genres"),
+        other => panic!("unexpected result: {:?}", other)
+    }
+}
+
+
+#[test]
+fn test_search_score() {
+    let hit = json::parse(r#"{ "score": 87 }"#).unwrap();
+    let legacy_hit = json::parse(r#"{ "score": "100" }"#).unwrap();
+
+    assert_eq!(search::score(&hit), Some(87));
+    assert_eq!(search::score(&legacy_hit), Some(100));
+}
+
+
+#[test]
+fn test_query_builder() {
+    let query = query::ArtistQuery::new()
+        .name("AC/DC")
+        .or().alias(query::Term::fuzzy("acdc", 1).unwrap())
+        .not().type_(enums::PersonType::Person)
+        .begin(query::Term::range("1970", "1979"));
+
+    // Clauses are combined from left to right.
+    assert_eq!(
+        query.to_string(),
+        r#"(artist:"AC/DC" OR alias:acdc~1) AND NOT type:"Person" AND begin:[1970 TO 1979]"#
+    );
+    let query = query::ArtistQuery::new().name("a").country("CA").or().name("b").country("GB");
+    assert_eq!(query.to_string(), r#"((artist:"a" AND country:"CA") OR artist:"b") AND country:"GB""#);
+    let query = query::ArtistQuery::new().name("a").or().group(query::ArtistQuery::new().name("b").country("GB"));
+    assert_eq!(query.to_string(), r#"artist:"a" OR (artist:"b" AND country:"GB")"#);
+
+    assert_eq!(query::ArtistQuery::new().name("a").group(query::ArtistQuery::new()).to_string(), r#"artist:"a""#);
+    assert!(query::Term::fuzzy("acdc", 3).is_err());
+    assert_eq!(query::escape("Sunn O)))"), r"Sunn\ O\)\)\)");
+    assert!(query::ArtistQuery::new().field("releasegroup", "foo").is_err());
+}
+
+
+#[test]
+fn test_include_param() {
+    use include::{Include, Request};
+
+    let musicbrainz = MusicBrainz::new();
+    let allowed = musicbrainz.artist().includes(Request::Lookup);
+
+    assert_eq!(
+        include::to_param(Request::Lookup, allowed, &[Include::Tags, Include::ReleaseGroups, Include::UrlRels]).unwrap(),
+        "tags+release-groups+url-rels"
+    );
+    assert!(include::to_param(Request::Lookup, allowed, &[Include::Labels]).is_err());
+    assert!(include::to_param(Request::Search, allowed, &[Include::Tags]).is_err());
+}
+
+
+#[test]
+fn test_partial_date() {
+    use partial_date::PartialDate;
+
+    let year = "2009".parse::<PartialDate>().unwrap();
+    let month = "2009-09".parse::<PartialDate>().unwrap();
+    let day = "2009-09-22".parse::<PartialDate>().unwrap();
+
+    assert_eq!(day, PartialDate::new(Some(2009), Some(9), Some(22)));
+    assert_eq!((year.to_string(), month.to_string(), day.to_string()), (String::from("2009"), String::from("2009-09"), String::from("2009-09-22")));
+    assert_eq!("".parse::<PartialDate>(), Ok(PartialDate::empty()));
+    for invalid in &["09", "2009-9", "2009-13", "2009-09-32", "2009-09-22-01", "2009/09"] {
+        assert!(invalid.parse::<PartialDate>().is_err(), "{} should not parse", invalid);
+    }
+
+    let mut dates = vec![day, "2009-10".parse().unwrap(), PartialDate::empty(), month, year, "2008-12-31".parse().unwrap()];
+    dates.sort();
+    let sorted: Vec<String> = dates.iter().map(|x| x.to_string()).collect();
+    assert_eq!(sorted, vec!["", "2008-12-31", "2009", "2009-09", "2009-09-22", "2009-10"]);
+}
+
+#[test]
+fn test_partial_date_unknown_parts() {
+    use partial_date::PartialDate;
+
+    let no_year = "????-04-12".parse::<PartialDate>().unwrap();
+    let no_month = "1987-??-03".parse::<PartialDate>().unwrap();
+
+    assert_eq!(no_year, PartialDate::new(None, Some(4), Some(12)));
+    assert_eq!(no_month, PartialDate::new(Some(1987), None, Some(3)));
+    assert_eq!((no_year.to_string(), no_month.to_string()), (String::from("????-04-12"), String::from("1987-??-03")));
+    assert_eq!("????-04".parse::<PartialDate>().unwrap().to_string(), "????-04");
+    assert_eq!("1987-??".parse::<PartialDate>().unwrap(), PartialDate::new(Some(1987), None, None));
+    for invalid in &["???-04-12", "1987-?-03", "19?7"] {
+        assert!(invalid.parse::<PartialDate>().is_err(), "{} should not parse", invalid);
+    }
+
+    let musicbrainz = MusicBrainz::with_transport(Box::new(FakeTransport(
+        200, r#"{ "id": "4a00ec9d-c635-463a-8cd4-eb61725f0c60", "name": "deadmau5", "type": "Person",
+            "relations": [{ "type": "official homepage", "direction": "forward", "begin": "????-01-05", "end": "2011-??-03",
+                            "target-type": "url", "url": { "id": "b3c2e7b4-3f4c-4cbd-93a4-50f8a2c2a8c1", "resource": "http://www.deadmau5.com/" } }] }"#
+    )));
+    let artist_id = Uuid::parse_str("4a00ec9d-c635-463a-8cd4-eb61725f0c60").unwrap();
+    let artist = musicbrainz.artist().lookup(&musicbrainz, &artist_id, &mut HashMap::new()).unwrap();
+    assert_eq!(artist.relations[0].begin, PartialDate::new(None, Some(1), Some(5)));
+    assert_eq!(artist.relations[0].end.to_string(), "2011-??-03");
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_partial_date_chrono() {
+    extern crate chrono;
+    use partial_date::PartialDate;
+
+    let date = "2009-09-22".parse::<PartialDate>().unwrap();
+    assert_eq!(date.to_naive_date(), chrono::NaiveDate::from_ymd_opt(2009, 9, 22));
+    assert_eq!(PartialDate::from(chrono::NaiveDate::from_ymd_opt(2009, 9, 22).unwrap()), date);
+    assert_eq!("2009-09".parse::<PartialDate>().unwrap().to_naive_date(), None);
+    assert_eq!("2009-02-30".parse::<PartialDate>().unwrap().to_naive_date(), None);
+}
+
+
+struct FakeTransport(u16, &'static str);
+
+impl transport::Transport for FakeTransport {
+    fn send(&self, request: &transport::Request) -> Result<transport::Response, Box<dyn std::error::Error + Send + Sync>> {
+        assert!(request.url.starts_with("https://musicbrainz.org/ws/2/artist/4a00ec9d-c635-463a-8cd4-eb61725f0c60"));
+
+        Ok(transport::Response {
+            status: self.0,
+            headers: Vec::new(),
+            body: String::from(self.1)
+        })
+    }
+}
+
+/// Answers every request with the given body.
+struct Body(&'static str);
+
+impl transport::Transport for Body {
+    fn send(&self, _request: &transport::Request) -> Result<transport::Response, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(transport::Response { status: 200, headers: Vec::new(), body: String::from(self.0) })
+    }
+}
+
+#[test]
+fn test_custom_transport() {
+    let musicbrainz = MusicBrainz::with_transport(Box::new(FakeTransport(
+        200, r#"{ "id": "4a00ec9d-c635-463a-8cd4-eb61725f0c60", "name": "deadmau5", "type": "Person" }"#
+    )));
+
+    let artist_id = Uuid::parse_str("4a00ec9d-c635-463a-8cd4-eb61725f0c60").unwrap();
+    let artist = musicbrainz.artist().lookup(&musicbrainz, &artist_id, &mut HashMap::new()).unwrap();
+
+    assert_eq!(artist.name, "deadmau5");
+}
+
+
+#[test]
+fn test_unknown_value_is_an_error() {
+    let musicbrainz = MusicBrainz::with_transport(Box::new(FakeTransport(
+        200, r#"{ "id": "4a00ec9d-c635-463a-8cd4-eb61725f0c60", "name": "deadmau5", "type": "Robot" }"#
+    )));
+
+    let artist_id = Uuid::parse_str("4a00ec9d-c635-463a-8cd4-eb61725f0c60").unwrap();
+    match musicbrainz.artist().lookup(&musicbrainz, &artist_id, &mut HashMap::new()) {
+        Err(error::Error::UnknownValue { path, value }) => assert_eq!((path.as_str(), value.as_str()), ("type", "Robot")),
+        other => panic!("unexpected result: {:?}", other)
+    }
+
+    let musicbrainz = MusicBrainz::with_transport(Box::new(Body(
+        r#"{ "id": "b9ba9c3e-0a0b-4d8f-8f5e-9a6d9c8e7f10", "title": "For Lack of a Better Name", "status": "Leaked" }"#
+    )));
+    let release_id = Uuid::parse_str("b9ba9c3e-0a0b-4d8f-8f5e-9a6d9c8e7f10").unwrap();
+    match musicbrainz.release().lookup(&musicbrainz, &release_id, &mut HashMap::new()) {
+        Err(error::Error::UnknownValue { path, value }) => assert_eq!((path.as_str(), value.as_str()), ("status", "Leaked")),
+        other => panic!("unexpected result: {:?}", other)
+    }
+}
+
+
+#[test]
+fn test_api_errors() {
+    let artist_id = Uuid::parse_str("4a00ec9d-c635-463a-8cd4-eb61725f0c60").unwrap();
+
+    let musicbrainz = MusicBrainz::with_transport(Box::new(FakeTransport(
+        404, r#"{ "error": "Not Found", "help": "For usage, please see: https://musicbrainz.org/development/mmd" }"#
+    )));
+    match musicbrainz.artist().lookup(&musicbrainz, &artist_id, &mut HashMap::new()) {
+        Err(error::Error::NotFound(e)) => {
+            assert_eq!(e.error, "Not Found");
+            assert!(e.help.is_some());
+        },
+        other => panic!("unexpected result: {:?}", other)
+    }
+
+    let musicbrainz = MusicBrainz::with_transport(Box::new(FakeTransport(
+        200, r#"{ "id": "4a00ec9d-c635-463a-8cd4-eb61725f0c60", "name": "deadmau5",
+                  "release-groups": [{ "id": "3c8a3b5e-0c5d-3a5b-8bd3-a8a8a7d6e11b", "title": "Random Album Title" },
+                                     { "id": "nope", "title": "4x4=12" }] }"#
+    )));
+    match musicbrainz.artist().lookup(&musicbrainz, &artist_id, &mut HashMap::new()) {
+        Err(error::Error::InvalidMbid { path, .. }) => assert_eq!(path, "release-groups[1].id"),
+        other => panic!("unexpected result: {:?}", other)
+    }
+
+    let musicbrainz = MusicBrainz::with_transport(Box::new(Body(
+        r#"{ "id": "b9ba9c3e-0a0b-4d8f-8f5e-9a6d9c8e7f10", "title": "For Lack of a Better Name",
+                  "label-info": [{ "catalog-number": "MAU5CD003", "label": null }, { "catalog-number": "UL 2095-2", "label": { "id": "nope" } }] }"#
+    )));
+    let release_id = Uuid::parse_str("b9ba9c3e-0a0b-4d8f-8f5e-9a6d9c8e7f10").unwrap();
+    match musicbrainz.release().lookup(&musicbrainz, &release_id, &mut HashMap::new()) {
+        Err(error::Error::InvalidMbid { path, .. }) => assert_eq!(path, "label-info[1].label.id"),
+        other => panic!("unexpected result: {:?}", other)
+    }
+
+    let musicbrainz = MusicBrainz::with_transport(Box::new(Body(
+        r#"{ "id": "b9ba9c3e-0a0b-4d8f-8f5e-9a6d9c8e7f10", "title": "For Lack of a Better Name",
+                  "media": [{ "position": 1, "discs": [], "tracks": [{ "id": "3c9c8a9e-3b1f-4a2e-9d7a-1f6c2e5b8d01", "title": "FML",
+                                                                        "recording": { "id": "nope" } }] }] }"#
+    )));
+    match musicbrainz.release().lookup(&musicbrainz, &release_id, &mut HashMap::new()) {
+        Err(error::Error::InvalidMbid { path, .. }) => assert_eq!(path, "media[0].tracks[0].recording.id"),
+        other => panic!("unexpected result: {:?}", other)
+    }
+
+    let label_id = Uuid::parse_str("8e1bd2c5-4d6b-4f0a-b0c4-2e1c3b5a7d90").unwrap();
+    let musicbrainz = MusicBrainz::with_transport(Box::new(Body(
+        r#"{ "id": "8e1bd2c5-4d6b-4f0a-b0c4-2e1c3b5a7d90", "name": "mau5trap", "aliases": [{ "sort-name": "mau5trap" }] }"#
+    )));
+    match musicbrainz.label().lookup(&musicbrainz, &label_id, &mut HashMap::new()) {
+        Err(error::Error::MissingField(path)) => assert_eq!(path, "aliases[0].name"),
+        other => panic!("unexpected result: {:?}", other)
+    }
+
+    let musicbrainz = MusicBrainz::with_transport(Box::new(Body(
+        r#"{ "id": "8e1bd2c5-4d6b-4f0a-b0c4-2e1c3b5a7d90", "name": "mau5trap", "type": "Cassette Pirate" }"#
+    )));
+    match musicbrainz.label().lookup(&musicbrainz, &label_id, &mut HashMap::new()) {
+        Err(error::Error::UnknownValue { path, value }) => assert_eq!((path.as_str(), value.as_str()), ("type", "Cassette Pirate")),
+        other => panic!("unexpected result: {:?}", other)
+    }
+
+    let musicbrainz = MusicBrainz::with_transport(Box::new(Body(
+        r#"{ "id": "8e1bd2c5-4d6b-4f0a-b0c4-2e1c3b5a7d90", "name": "mau5trap", "type": 3 }"#
+    )));
+    match musicbrainz.label().lookup(&musicbrainz, &label_id, &mut HashMap::new()) {
+        Err(error::Error::InvalidType { path, expected }) => assert_eq!((path.as_str(), expected), ("type", "string")),
+        other => panic!("unexpected result: {:?}", other)
+    }
+
+    let musicbrainz = MusicBrainz::with_transport(Box::new(Body(r#"{ "id": "8e1bd2c5-4d6b-4f0a-b0c4-2e1c3b5a7d90" }"#)));
+    for result in vec![
+        musicbrainz.label().lookup(&musicbrainz, &label_id, &mut HashMap::new()).map(|_| ()),
+        musicbrainz.area().lookup(&musicbrainz, &label_id, &mut HashMap::new()).map(|_| ()),
+        musicbrainz.event().lookup(&musicbrainz, &label_id, &mut HashMap::new()).map(|_| ())
+    ] {
+        match result {
+            Err(error::Error::MissingField(path)) => assert_eq!(path, "name"),
+            other => panic!("unexpected result: {:?}", other)
+        }
+    }
+}
+
+#[cfg(feature = "xml")]
+#[test]
+fn test_xml_errors() {
+    let artist_id = Uuid::parse_str("4a00ec9d-c635-463a-8cd4-eb61725f0c60").unwrap();
+
+    let mut musicbrainz = MusicBrainz::with_transport(Box::new(FakeTransport(
+        404, "<?xml version=\"1.0\" encoding=\"UTF-8\"?><error><text>Not Found</text><text>For usage, please see: https://musicbrainz.org/development/mmd</text></error>"
+    )));
+    musicbrainz.set_format(format::Format::Xml);
+    match musicbrainz.artist().lookup(&musicbrainz, &artist_id, &mut HashMap::new()) {
+        Err(error::Error::NotFound(e)) => {
+            assert_eq!(e.error, "Not Found");
+            assert!(e.help.is_some());
+        },
+        other => panic!("unexpected result: {:?}", other)
+    }
+
+    let mut musicbrainz = MusicBrainz::with_transport(Box::new(FakeTransport(200, "<metadata><artist>")));
+    musicbrainz.set_format(format::Format::Xml);
+    match musicbrainz.artist().lookup(&musicbrainz, &artist_id, &mut HashMap::new()) {
+        Err(error::Error::ParseXml(_)) => {},
+        other => panic!("unexpected result: {:?}", other)
+    }
+}
+
+
+struct CaptureTransport(std::sync::Arc<std::sync::Mutex<Vec<transport::Request>>>);
+
+impl transport::Transport for CaptureTransport {
+    fn send(&self, request: &transport::Request) -> Result<transport::Response, Box<dyn std::error::Error + Send + Sync>> {
+        self.0.lock().unwrap().push(request.clone());
+
+        Ok(transport::Response {
+            status: 200,
+            headers: Vec::new(),
+            body: String::from(r#"{ "id": "4a00ec9d-c635-463a-8cd4-eb61725f0c60", "name": "deadmau5" }"#)
+        })
+    }
+}
+
+#[test]
+fn test_builder() {
+    let requests = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    let musicbrainz = MusicBrainz::builder()
+        .base_url("http://localhost:5000/ws/2/")
+        .application("tagger", "1.0", "tagger@example.org")
+        .transport(Box::new(CaptureTransport(requests.clone())))
+        .build()
+        .unwrap();
+
+    let artist_id = Uuid::parse_str("4a00ec9d-c635-463a-8cd4-eb61725f0c60").unwrap();
+    musicbrainz.artist().lookup(&musicbrainz, &artist_id, &mut HashMap::new()).unwrap();
+
+    let request = requests.lock().unwrap()[0].clone();
+    assert!(request.url.starts_with("http://localhost:5000/ws/2/artist/4a00ec9d-c635-463a-8cd4-eb61725f0c60?"));
+    assert!(request.headers.iter().any(|&(ref name, ref value)| name == "User-Agent" && value.starts_with("tagger/1.0 ( tagger@example.org ) musicbrainz/")));
+
+    match MusicBrainz::builder().base_url("not a url").build() {
+        Err(error::Error::InvalidUrl(_)) => (),
+        other => panic!("unexpected result: {:?}", other)
+    }
+}
+
+
+#[test]
+fn test_rate_limiter() {
+    use rate_limit::RateLimiter;
+    use std::time::{Duration, Instant};
+
+    let limiter = RateLimiter::new(1, Duration::from_millis(100));
+    let start = Instant::now();
+    for _ in 0..3 {
+        limiter.acquire();
+    }
+
+    assert!(start.elapsed() >= Duration::from_millis(190));
+    assert!(RateLimiter::default_for("https://musicbrainz.org/ws/2").is_some());
+    assert!(RateLimiter::default_for("http://localhost:5000/ws/2").is_none());
+}
+
+
+struct FlakyTransport {
+    status: u16,
+    failures: usize,
+    attempts: std::sync::atomic::AtomicUsize
+}
+
+impl transport::Transport for FlakyTransport {
+    fn send(&self, _request: &transport::Request) -> Result<transport::Response, Box<dyn std::error::Error + Send + Sync>> {
+        let attempt = self.attempts.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        if attempt < self.failures {
+            return Ok(transport::Response {
+                status: self.status,
+                headers: vec![(String::from("Retry-After"), String::from("0"))],
+                body: String::from("Service Temporarily Unavailable")
+            });
+        }
+
+        Ok(transport::Response {
+            status: 200,
+            headers: Vec::new(),
+            body: String::from(r#"{ "id": "4a00ec9d-c635-463a-8cd4-eb61725f0c60", "name": "deadmau5", "type": "Person" }"#)
+        })
+    }
+}
+
+#[test]
+fn test_retry_on_503() {
+    let artist_id = Uuid::parse_str("4a00ec9d-c635-463a-8cd4-eb61725f0c60").unwrap();
+    let flaky = |failures| FlakyTransport { status: 503, failures: failures, attempts: std::sync::atomic::AtomicUsize::new(0) };
+
+    let mut musicbrainz = MusicBrainz::with_transport(Box::new(flaky(2)));
+    musicbrainz.set_rate_limit(None);
+    assert!(musicbrainz.artist().lookup(&musicbrainz, &artist_id, &mut HashMap::new()).is_ok());
+
+    let mut musicbrainz = MusicBrainz::with_transport(Box::new(flaky(10)));
+    musicbrainz.set_rate_limit(None);
+    match musicbrainz.artist().lookup(&musicbrainz, &artist_id, &mut HashMap::new()) {
+        Err(error::Error::Throttled { error, attempts }) => assert_eq!((error.status, attempts), (503, 5)),
+        other => panic!("unexpected result: {:?}", other)
+    }
+
+    // Every status that is retried reports the attempts once they are exhausted.
+    let transport = FlakyTransport { status: 502, failures: 10, attempts: std::sync::atomic::AtomicUsize::new(0) };
+    let mut musicbrainz = MusicBrainz::with_transport(Box::new(transport));
+    musicbrainz.set_rate_limit(None);
+    match musicbrainz.artist().lookup(&musicbrainz, &artist_id, &mut HashMap::new()) {
+        Err(error::Error::Throttled { error, attempts }) => assert_eq!((error.status, attempts), (502, 5)),
+        other => panic!("unexpected result: {:?}", other)
+    }
+
+    // Without retries, a server error is not mistaken for throttling.
+    let transport = FlakyTransport { status: 500, failures: 10, attempts: std::sync::atomic::AtomicUsize::new(0) };
+    let mut musicbrainz = MusicBrainz::with_transport(Box::new(transport));
+    musicbrainz.set_rate_limit(None);
+    musicbrainz.set_retry_policy(retry::RetryPolicy::none());
+    match musicbrainz.artist().lookup(&musicbrainz, &artist_id, &mut HashMap::new()) {
+        Err(error::Error::Status(error)) => assert_eq!(error.status, 500),
+        other => panic!("unexpected result: {:?}", other)
+    }
+}
+
+#[test]
+fn test_retry_after() {
+    use std::time::Duration;
+
+    let policy = retry::RetryPolicy { initial_backoff: Duration::from_secs(7), ..retry::RetryPolicy::default() };
+    let response = |retry_after: &str| transport::Response {
+        status: 503,
+        headers: vec![(String::from("Retry-After"), retry_after.to_string())],
+        body: String::new()
+    };
+
+    assert_eq!(policy.backoff(1, &response("3")), Duration::from_secs(3));
+    assert_eq!(policy.backoff(1, &response("Sun, 06 Nov 1994 08:49:37 GMT")), Duration::from_secs(0));
+    assert_eq!(policy.backoff(1, &response("Fri, 31 Dec 9999 23:59:59 GMT")), policy.max_backoff);
+    assert_eq!(policy.backoff(1, &response("soon")), Duration::from_secs(7));
+    // Dates too far in the future are ignored rather than overflowing.
+    assert_eq!(policy.backoff(1, &response("Sun, 06 Nov 500000000000 08:49:37 GMT")), Duration::from_secs(7));
+    assert_eq!(policy.backoff(1, &response("Sun, 06 Nov 18446744073709551615 08:49:37 GMT")), Duration::from_secs(7));
+}
+
+#[test]
+fn test_artist_credit() {
+    let musicbrainz = MusicBrainz::with_transport(Box::new(Body(
+        r#"{ "id": "0b6f0e47-1d83-4d7a-9f3e-6c2a5b8e1d44", "title": "Move for Me", "primary-type": "Single",
+                  "artist-credit": [
+                      { "name": "Kaskade", "joinphrase": " & ",
+                        "artist": { "id": "a7c6d5a1-2a6b-4a0c-9a3e-0c1b2d3e4f50", "name": "Kaskade", "sort-name": "Kaskade" } },
+                      { "name": "Deadmau5", "joinphrase": "",
+                        "artist": { "id": "4a00ec9d-c635-463a-8cd4-eb61725f0c60", "name": "deadmau5", "sort-name": "deadmau5" } }
+                  ] }"#
+    )));
+    let release_group_id = Uuid::parse_str("0b6f0e47-1d83-4d7a-9f3e-6c2a5b8e1d44").unwrap();
+    let release_group = musicbrainz.release_group().lookup(&musicbrainz, &release_group_id, &mut HashMap::new()).unwrap();
+
+    let credit = release_group.artist_credit;
+    assert_eq!(credit.display_name(), "Kaskade & Deadmau5");
+    assert_eq!(credit.to_string(), "Kaskade & Deadmau5");
+    assert_eq!(credit.artists()[0].name, "Kaskade");
+    assert_eq!(credit.credits[1].name, "Deadmau5");
+    assert_eq!(credit.credits[1].artist.name, "deadmau5");
+    assert_eq!(credit.credits[1].artist.id.hyphenated().to_string(), "4a00ec9d-c635-463a-8cd4-eb61725f0c60");
+
+    let musicbrainz = MusicBrainz::with_transport(Box::new(Body(
+        r#"{ "id": "0b6f0e47-1d83-4d7a-9f3e-6c2a5b8e1d44", "title": "Move for Me",
+                  "artist-credit": [{ "name": "Kaskade", "artist": { "name": "Kaskade" } }] }"#
+    )));
+    match musicbrainz.release_group().lookup(&musicbrainz, &release_group_id, &mut HashMap::new()) {
+        Err(error::Error::MissingField(path)) => assert_eq!(path, "artist-credit[0].artist.id"),
+        other => panic!("unexpected result: {:?}", other)
+    }
+}
@@ -0,0 +1,87 @@
+//! Tests of the `serde` implementations, run with `cargo test --features serde`.
+
+extern crate musicbrainz;
+extern crate serde_json;
+
+use musicbrainz::*;
+use musicbrainz::artist::Artist;
+use musicbrainz::enums::PersonType;
+use musicbrainz::release_group::ReleaseGroup;
+use musicbrainz::search::SearchResult;
+use musicbrainz::transport::{Transport, Request, Response};
+use std::collections::HashMap;
+
+const ARTIST: &'static str = include_str!("fixtures/artist/4a00ec9d-c635-463a-8cd4-eb61725f0c60/fmt=json&inc=release-groups+tags.json");
+const ARTIST_RELATIONS: &'static str = include_str!("fixtures/artist/4a00ec9d-c635-463a-8cd4-eb61725f0c60/fmt=json&inc=artist-rels+series-rels+url-rels.json");
+const RELEASE_GROUPS: &'static str = include_str!("fixtures/release-group/fmt=json&query=releasegroup%3A%22For%20Lack%20of%20a%20Better%20Name%22%20AND%20artist%3A%22deadmau5%22.json");
+
+struct Body(&'static str);
+
+impl Transport for Body {
+    fn send(&self, _request: &Request) -> Result<Response, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(Response {
+            status: 200,
+            headers: Vec::new(),
+            body: String::from(self.0)
+        })
+    }
+}
+
+#[test]
+fn test_deserialize_web_service_json() {
+    let artist: Artist = serde_json::from_str(ARTIST).unwrap();
+
+    let mut musicbrainz = MusicBrainz::with_transport(Box::new(Body(ARTIST)));
+    musicbrainz.set_rate_limit(None);
+    let parsed = musicbrainz.artist().lookup(&musicbrainz, &artist.id, &mut HashMap::new()).unwrap();
+
+    assert_eq!(artist, parsed);
+    assert_eq!(artist.artist_type, PersonType::Person);
+    assert_eq!(artist.tags, parsed.tags);
+    assert_eq!(artist.release_groups.len(), parsed.release_groups.len());
+    for (deserialized, parsed) in artist.release_groups.iter().zip(parsed.release_groups.iter()) {
+        assert_eq!(deserialized.id, parsed.id);
+        assert_eq!(deserialized.release_date, parsed.release_date);
+        assert_eq!(deserialized.primary_type, parsed.primary_type);
+    }
+}
+
+#[test]
+fn test_deserialize_search_hit() {
+    let data: serde_json::Value = serde_json::from_str(RELEASE_GROUPS).unwrap();
+    let hit: SearchResult<ReleaseGroup> = serde_json::from_value(data["release-groups"][0].clone()).unwrap();
+
+    assert_eq!(hit.score, 100);
+    assert_eq!(hit.entity.title, "For Lack of a Better Name");
+    assert_eq!(hit.entity.artist_credit.display_name(), "deadmau5");
+    assert_eq!(hit.entity.artist_credit.credits[0].artist.id.hyphenated().to_string(), "4a00ec9d-c635-463a-8cd4-eb61725f0c60");
+}
+
+#[test]
+fn test_serialize_round_trip() {
+    let artist: Artist = serde_json::from_str(ARTIST).unwrap();
+
+    let serialized = serde_json::to_value(&artist).unwrap();
+    assert_eq!(serialized["type"], "Person");
+    assert!(serialized["release-groups"][0]["first-release-date"].is_string());
+
+    let deserialized: Artist = serde_json::from_value(serialized.clone()).unwrap();
+    assert_eq!(serde_json::to_value(&deserialized).unwrap(), serialized);
+}
+
+#[test]
+fn test_relationships() {
+    let artist: Artist = serde_json::from_str(ARTIST_RELATIONS).unwrap();
+
+    let mut musicbrainz = MusicBrainz::with_transport(Box::new(Body(ARTIST_RELATIONS)));
+    musicbrainz.set_rate_limit(None);
+    let parsed = musicbrainz.artist().lookup(&musicbrainz, &artist.id, &mut HashMap::new()).unwrap();
+    assert_eq!(artist.relations, parsed.relations);
+
+    let serialized = serde_json::to_value(&artist).unwrap();
+    assert_eq!(serialized["relations"][2]["target-type"], "url");
+    assert_eq!(serialized["relations"][2]["url"]["resource"], "http://www.deadmau5.com/");
+
+    let deserialized: Artist = serde_json::from_value(serialized).unwrap();
+    assert_eq!(deserialized.relations, artist.relations);
+}